@@ -0,0 +1,41 @@
+//! Selects t1ha0 implementation, best suited for the target CPU features,
+//! which is then benchmarked as `t1ha_native`.
+//! AES-NI variants are used only if AES-NI is enabled (for example, with `-C target-cpu=native`).
+//! Also records short git SHA of the current commit (or `unknown`), reported by `--version` and in output files.
+
+use std::{
+    env,
+    path::Path,
+    process::Command,
+};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=CARGO_CFG_TARGET_FEATURE");
+    println!("cargo:rustc-check-cfg=cfg(t1ha_native, values(\"avx2\", \"avx\", \"aes\", \"portable\"))");
+
+    let features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let has = |feature: &str| features.split(',').any(|f| f == feature);
+    let variant = if has("aes") && has("avx2") {
+        "avx2"
+    } else if has("aes") && has("avx") {
+        "avx"
+    } else if has("aes") {
+        "aes"
+    } else {
+        "portable"
+    };
+    println!("cargo:rustc-cfg=t1ha_native=\"{}\"", variant);
+    println!("cargo:rustc-env=T1HA_NATIVE_VARIANT={}", variant);
+
+    if Path::new(".git/HEAD").exists() {
+        println!("cargo:rerun-if-changed=.git/HEAD");
+        println!("cargo:rerun-if-changed=.git/refs");
+    }
+    let git_sha = Command::new("git").args(["rev-parse", "--short", "HEAD"]).output().ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|sha| sha.trim().to_owned())
+        .unwrap_or_else(|| "unknown".to_owned());
+    println!("cargo:rustc-env=HASH_BENCH_GIT_SHA={}", git_sha);
+}
@@ -0,0 +1,30 @@
+#!/usr/bin/env bash
+# Measures release build time of each hasher crate in an otherwise empty project,
+# and writes it to out/compile_times.csv (tab-separated, same as other outputs).
+# Dependency specifications are copied from Cargo.toml, downloads are excluded from the timing.
+set -euo pipefail
+
+root="$(cd "$(dirname "$0")/.." && pwd)"
+crates=(siphasher ahash seahash metrohash rustc-hash wyhash wyhash2 xxhash-rust highway fasthash fnv)
+tmp="$(mktemp -d)"
+trap 'rm -rf "$tmp"' EXIT
+
+mkdir -p "$root/out"
+output="$root/out/compile_times.csv"
+printf 'crate\tcompile_time_s\n' > "$output"
+for crate in "${crates[@]}"; do
+    dependency="$(grep -E "^${crate} = " "$root/Cargo.toml")"
+    project="$tmp/$crate"
+    cargo new --lib --quiet --name "compile_time_${crate//-/_}" "$project"
+    echo "$dependency" >> "$project/Cargo.toml"
+    cargo fetch --quiet --manifest-path "$project/Cargo.toml"
+
+    echo "Building $crate" >&2
+    start="$(date +%s%N)"
+    cargo build --release --quiet --manifest-path "$project/Cargo.toml"
+    end="$(date +%s%N)"
+    ms=$(( (end - start) / 1000000 ))
+    seconds="$(printf '%d.%03d' $(( ms / 1000 )) $(( ms % 1000 )))"
+    echo "    -> $seconds s" >&2
+    printf '%s\t%s\n' "$crate" "$seconds" >> "$output"
+done
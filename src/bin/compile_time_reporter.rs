@@ -0,0 +1,24 @@
+//! Adds compile times, measured by `scripts/bench_compile_time.sh`, to the bandwidth summary.
+//! Usage: `compile_time_reporter [OUT_DIR]` (`out` by default), after running `hash_bench --bandwidth`.
+
+#[allow(dead_code)]
+#[path = "../platform.rs"]
+mod platform;
+#[allow(dead_code)]
+#[path = "../report.rs"]
+mod report;
+#[allow(dead_code)]
+#[path = "../stats.rs"]
+mod stats;
+
+use std::path::PathBuf;
+
+fn main() {
+    let out_dir = PathBuf::from(std::env::args().nth(1).unwrap_or_else(|| "out".to_owned()));
+    let summary_path = out_dir.join("summary.csv");
+    if !summary_path.exists() {
+        report::postprocess_bandwidth(&out_dir.join("bandwidth.csv")).unwrap();
+    }
+    report::postprocess_compile_times(&summary_path, &out_dir.join("compile_times.csv")).unwrap();
+    eprintln!("Saved {}", out_dir.join("summary_compile_time.csv").display());
+}
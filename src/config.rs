@@ -0,0 +1,426 @@
+use std::{
+    path::PathBuf,
+    process,
+    str::FromStr,
+};
+
+/// Prints error message and exits.
+fn fail(msg: &str) -> ! {
+    eprintln!("{}", msg);
+    process::exit(1)
+}
+
+/// Returns next argument, parsed as a value for `flag`.
+fn next_value<T: FromStr>(args: &mut impl Iterator<Item = String>, flag: &str) -> T {
+    let value = args.next().unwrap_or_else(|| fail(&format!("Missing value for {}", flag)));
+    value.parse().unwrap_or_else(|_| fail(&format!("Cannot parse value {:?} for {}", value, flag)))
+}
+
+/// Pre-defined groups of hashers, selected with `--hasher-groups`.
+/// Names match the names of the benchmarked hashers, including their 128-bit variants.
+pub const HASHER_GROUPS: &[(&str, &[&str])] = &[
+    ("fast", &["ahash", "ahash_ctrng", "wyhash", "wyhash2", "xxhash64", "xxh64_fasthash"]),
+    ("crypto", &["sip13", "sip24"]),
+    ("legacy", &["fnv", "murmur2", "city", "city128", "spooky", "spooky128", "farm", "farm128", "lookup3"]),
+];
+
+/// Raw hash values of random inputs, saved for external analysis.
+pub struct SaveHashes {
+    pub hasher: String,
+    pub size: usize,
+    pub count: usize,
+}
+
+/// Range of input sizes for the bandwidth benchmark, parsed from `<min>-<max>:<step>`.
+pub struct SizeRange {
+    pub min: usize,
+    pub max: usize,
+    pub step: usize,
+}
+
+impl FromStr for SizeRange {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let (range, step) = s.split_once(':').ok_or(())?;
+        let (min, max) = range.split_once('-').ok_or(())?;
+        let range = Self {
+            min: min.parse().map_err(|_| ())?,
+            max: max.parse().map_err(|_| ())?,
+            step: step.parse().map_err(|_| ())?,
+        };
+        if range.min == 0 || range.min > range.max || range.step == 0 {
+            return Err(());
+        }
+        Ok(range)
+    }
+}
+
+/// Weights of speed, collisions and randomness in the quality score, parsed from `<speed>:<collision>:<randomness>`.
+pub struct QualityWeights {
+    pub speed: f64,
+    pub collision: f64,
+    pub randomness: f64,
+}
+
+impl Default for QualityWeights {
+    fn default() -> Self {
+        Self { speed: 1.0, collision: 1.0, randomness: 1.0 }
+    }
+}
+
+impl FromStr for QualityWeights {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let values = s.split(':').map(|v| v.parse::<f64>().map_err(|_| ())).collect::<Result<Vec<_>, _>>()?;
+        let &[speed, collision, randomness] = &values[..] else { return Err(()) };
+        if values.iter().any(|v| !v.is_finite() || *v < 0.0) || speed + collision + randomness <= 0.0 {
+            return Err(());
+        }
+        Ok(Self { speed, collision, randomness })
+    }
+}
+
+/// Which benchmarks to run, parsed from the command line.
+/// Without any arguments, bandwidth, collisions and randomness are evaluated.
+#[derive(Default)]
+pub struct Config {
+    pub bandwidth: bool,
+    pub collisions: bool,
+    pub randomness: bool,
+    /// Collisions on strings with common file extensions and variable prefix.
+    pub suffix_test: bool,
+    /// Correlation between two halves of 128-bit hashers.
+    pub independence_128: bool,
+    /// Hashes of inputs that contain a single zero byte at various positions.
+    pub null_byte: bool,
+    /// Collisions and randomness on UNIX timestamp keys.
+    pub timestamps: bool,
+    /// Compare hashes of inputs written at once and in chunks.
+    pub streaming: bool,
+    /// Bandwidth depending on the number of consecutive hashes per timing iteration.
+    pub pipeline_effect: bool,
+    /// Compare hashes of strings and their reverse.
+    pub palindrome: bool,
+    /// Bandwidth on input sizes, processed by different SIMD widths.
+    pub simd_sizes: bool,
+    /// Collisions and randomness on adversarial inputs with alternating bits.
+    pub alternating_bits: bool,
+    /// Evaluate bandwidth on all sizes in the range instead of the default sizes.
+    pub size_range: Option<SizeRange>,
+    /// Record resident set size before and after each pass over a hasher (general, 128-bit, seedable and cloneable
+    /// benchmarks), as well as the peak during the pass (Linux only).
+    pub memory: bool,
+    /// Use median and median absolute deviation instead of mean and standard deviation for bandwidth
+    /// and timing measurements of all benchmarks. Statistics of the quality tests are not affected.
+    pub robust_stats: bool,
+    /// Fraction of the lowest and of the highest bandwidth values, discarded from the mean (0 = regular mean).
+    pub trim_fraction: f64,
+    /// Weights of the quality score components (all equal by default).
+    pub weights: QualityWeights,
+    /// Number of changed leading bytes, required to change a quarter of output bits.
+    pub convergence: bool,
+    /// Time to compute chains of hashes, each seeded with the previous hash.
+    pub chained: bool,
+    /// Collisions and randomness on key sets with power-of-two sizes.
+    pub power_of_two: bool,
+    /// Search for colliding input pairs within a fixed budget.
+    pub hash_dos: bool,
+    /// Bandwidth when the input is written in chunks of increasing size.
+    pub incremental: bool,
+    /// Compare hashes of strings and their proper prefixes.
+    pub prefix_free: bool,
+    /// Kolmogorov-Smirnov test of hash values against the uniform distribution.
+    pub ks_test: bool,
+    /// Collisions and randomness on Ethernet frames with a fixed header.
+    pub network_packets: bool,
+    /// Compare hashes of strings and the same strings, padded with zero bytes.
+    pub length_independence: bool,
+    /// Number of timing iterations, required for stable bandwidth measurements.
+    pub stability_iters: bool,
+    /// Uniformity of hash values modulo common hash table sizes.
+    pub distribution_mod: bool,
+    /// CPU cycles per hash on short keys (x86_64 only).
+    pub cycles: bool,
+    /// Collisions and randomness on HEX-encoded SHA256-like digests.
+    pub hex_sha256: bool,
+    /// Collisions when two fields are hashed together or hashed separately and combined with XOR.
+    pub xor_combine: bool,
+    /// Time to build a hash map with a million entries.
+    pub mass_insert: bool,
+    /// Hash map lookups of present and absent keys.
+    pub lookup: bool,
+    /// Bandwidth when the hasher state is cloned in the middle of the input.
+    pub clone_cost: bool,
+    /// Collisions and randomness on structs with different values of padding bytes.
+    pub struct_padding: bool,
+    /// Strict avalanche criterion depending on the input length.
+    pub sac: bool,
+    /// Bandwidth on input sizes around multiples of typical block sizes.
+    pub block_boundaries: bool,
+    /// Range of hash values and distribution of their top 8 bits.
+    pub output_range: bool,
+    /// Print size and alignment of hasher states, without running any benchmarks.
+    pub hasher_impl_check: bool,
+    /// Collisions and randomness on base64-encoded random data, compared with the same raw data.
+    pub base64: bool,
+    /// Bandwidth on inputs at random offsets of a 1 GiB pool, compared with a cached buffer.
+    pub cache_miss: bool,
+    /// Collisions on the same inputs, hashed in sorted and in random order.
+    pub sorted_vs_shuffled: bool,
+    /// Run-length encoding ratio of the stream of hash values.
+    pub compressibility: bool,
+    /// Collisions on long strings (64-4096 bytes) with variable suffix.
+    pub collisions_long: bool,
+    /// Number of changed output bits for each changed input byte position.
+    pub randomness_by_position: bool,
+    /// Bandwidth depending on the offset of the input from the buffer start.
+    pub alignment_sweep: bool,
+    /// Measure bandwidth on all files from this directory.
+    pub file_dir: Option<PathBuf>,
+    /// Collisions on strings with variable 2-byte prefix, middle or suffix.
+    pub variant_position: bool,
+    /// Hashes of the empty input and of a single zero byte.
+    pub empty_input: bool,
+    /// Compare hashes of strings and the same strings, followed by a few zero bytes.
+    pub zero_suffix: bool,
+    /// Bandwidth when the input is written in many small calls, compared with a single call.
+    pub write_overhead: bool,
+    /// Aggregate bandwidth of several independent hashers, interleaved in the same thread.
+    pub pipeline_depth: bool,
+    /// Bandwidth on inputs with random prefix and constant suffix of varying lengths.
+    pub entropy: bool,
+    /// Uniformity of the parity of hash values.
+    pub parity: bool,
+    /// Collisions and randomness on Kubernetes-like pod names with variable 5-character suffix.
+    pub kubernetes_pods: bool,
+    /// Bandwidth of hashing a large buffer at once and as many separate chunks.
+    pub bulk_vs_chunks: bool,
+    /// Collisions on keys with a common prefix, that differ only in the last few bytes.
+    pub sibling_collisions: bool,
+    /// Correlation between hashes of prefixes of the same string with different lengths.
+    pub length_autocorrelation: bool,
+    /// Bandwidth of single hash calls after cache eviction, compared with warm calls.
+    pub icache: bool,
+    /// Bandwidth file of a previous run (copied outside of `out/`), compared with the current bandwidth
+    /// for each input size.
+    pub baseline: Option<PathBuf>,
+    /// Randomness on sequential integer inputs instead of random inputs.
+    pub randomness_structured: bool,
+    /// Bandwidth of hashing several fields separately and combining them with XOR.
+    pub combine: bool,
+    /// Frequencies of transitions between adjacent output bits.
+    pub bit_transitions: bool,
+    /// Number of random inputs, tried until the low bits of the hash match a target.
+    pub preimage: bool,
+    /// Collisions and randomness on common JSON field names with numeric suffixes.
+    pub json_keys: bool,
+    /// Wald-Wolfowitz runs test on the lowest bit of hash values.
+    pub runs_test: bool,
+    /// Variance of the collision rate across seeds of seedable hashers.
+    pub seeded_security: bool,
+    /// Bandwidth when each input depends on the previous hash value.
+    pub data_dependent: bool,
+    /// Collisions and randomness on keys with a common body and distinct random salts.
+    pub salted_keys: bool,
+    /// Number of changed output bits when only the last input byte changes.
+    pub finalization: bool,
+    /// Distribution of hash values of all 1- and 2-byte inputs and a sample of 3-byte inputs.
+    pub small_input_range: bool,
+    /// Number of changed output bits when a random half of input bits is flipped.
+    pub half_avalanche: bool,
+    /// Chi-squared test of byte value frequencies at each byte position of hash values.
+    pub byte_frequency: bool,
+    /// Time of hasher construction, separately from hashing.
+    pub init_cost: bool,
+    /// Bandwidth of hashers, used through `&mut dyn Hasher`.
+    pub dyn_dispatch: bool,
+    /// Maximal and 99th percentile bucket depth in a hash table with various load factors.
+    pub bucket_depth: bool,
+    /// Time of hashing bincode-serialized structs compared with `Hash::hash` (requires `serde-test` feature).
+    pub serde_hash: bool,
+    /// Aggregate bandwidth of several threads, each running its own hasher.
+    pub scaling: bool,
+    /// Whether hashers, created by `Default::default()`, produce identical or independent hashes.
+    pub reset_independence: bool,
+    /// Save hashes of the fixed input set (see `vectors.rs`) to this file.
+    pub export_vectors: Option<PathBuf>,
+    /// Compare hashes of the fixed input set with the file, exported on another machine.
+    pub verify_vectors: Option<PathBuf>,
+    /// Fraction of hash values in the top 1% of the output range.
+    pub high_values: bool,
+    /// Save raw hashes of one hasher to a binary file.
+    pub save_hashes: Option<SaveHashes>,
+    /// Together with `save_hashes`, also save the inputs.
+    pub save_inputs: bool,
+    /// Only run these hashers (all hashers if None).
+    pub hashers: Option<Vec<String>>,
+}
+
+impl Config {
+    /// Returns true if any of the generated datasets needs to be tested.
+    pub fn any_dataset(&self) -> bool {
+        self.timestamps || self.alternating_bits || self.power_of_two || self.network_packets || self.hex_sha256
+            || self.struct_padding || self.base64 || self.kubernetes_pods || self.json_keys || self.salted_keys
+    }
+
+    /// Returns true if the hasher should be benchmarked.
+    pub fn hasher_enabled(&self, name: &str) -> bool {
+        self.hashers.as_ref().is_none_or(|hashers| hashers.iter().any(|hasher| hasher == name))
+    }
+
+    pub fn from_args() -> Self {
+        let mut config = Self::default();
+        let mut selected = false;
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match &arg as &str {
+                "--bandwidth" => config.bandwidth = true,
+                "--collisions" => config.collisions = true,
+                "--randomness" => config.randomness = true,
+                "--suffix-test" => config.suffix_test = true,
+                "--independence-128" => config.independence_128 = true,
+                "--null-byte" => config.null_byte = true,
+                "--timestamps" => config.timestamps = true,
+                "--streaming" => config.streaming = true,
+                "--pipeline-effect" => config.pipeline_effect = true,
+                "--palindrome" => config.palindrome = true,
+                "--simd-sizes" => config.simd_sizes = true,
+                "--alternating-bits" => config.alternating_bits = true,
+                "--convergence" => config.convergence = true,
+                "--chained" => config.chained = true,
+                "--power-of-two" => config.power_of_two = true,
+                "--hash-dos" => config.hash_dos = true,
+                "--incremental" => config.incremental = true,
+                "--prefix-free" => config.prefix_free = true,
+                "--ks-test" => config.ks_test = true,
+                "--network-packets" => config.network_packets = true,
+                "--length-independence" => config.length_independence = true,
+                "--stability-iters" => config.stability_iters = true,
+                "--distribution-mod" => config.distribution_mod = true,
+                "--cycles" => config.cycles = true,
+                "--hex-sha256" => config.hex_sha256 = true,
+                "--xor-combine" => config.xor_combine = true,
+                "--mass-insert" => config.mass_insert = true,
+                "--lookup" => config.lookup = true,
+                "--clone-cost" => config.clone_cost = true,
+                "--struct-padding" => config.struct_padding = true,
+                "--sac" => config.sac = true,
+                "--block-boundaries" => config.block_boundaries = true,
+                "--size-range" => {
+                    config.bandwidth = true;
+                    config.size_range = Some(next_value(&mut args, &arg));
+                }
+                "--output-range" => config.output_range = true,
+                "--hasher-impl-check" => config.hasher_impl_check = true,
+                "--base64" => config.base64 = true,
+                "--cache-miss" => config.cache_miss = true,
+                "--sorted-vs-shuffled" => config.sorted_vs_shuffled = true,
+                "--compressibility" => config.compressibility = true,
+                "--collisions-long" => config.collisions_long = true,
+                "--randomness-by-position" => config.randomness_by_position = true,
+                "--alignment-sweep" => config.alignment_sweep = true,
+                "--file-dir" => config.file_dir = Some(next_value(&mut args, &arg)),
+                "--variant-position" => config.variant_position = true,
+                "--empty-input" => config.empty_input = true,
+                "--zero-suffix" => config.zero_suffix = true,
+                "--write-overhead" => config.write_overhead = true,
+                "--pipeline-depth" => config.pipeline_depth = true,
+                "--entropy" => config.entropy = true,
+                "--parity" => config.parity = true,
+                "--kubernetes-pods" => config.kubernetes_pods = true,
+                "--bulk-vs-chunks" => config.bulk_vs_chunks = true,
+                "--sibling-collisions" => config.sibling_collisions = true,
+                "--length-autocorrelation" => config.length_autocorrelation = true,
+                "--icache" => config.icache = true,
+                "--randomness-structured" => config.randomness_structured = true,
+                "--combine" => config.combine = true,
+                "--bit-transitions" => config.bit_transitions = true,
+                "--preimage" => config.preimage = true,
+                "--json-keys" => config.json_keys = true,
+                "--runs-test" => config.runs_test = true,
+                "--seeded-security" => config.seeded_security = true,
+                "--data-dependent" => config.data_dependent = true,
+                "--salted-keys" => config.salted_keys = true,
+                "--finalization" => config.finalization = true,
+                "--small-input-range" => config.small_input_range = true,
+                "--half-avalanche" => config.half_avalanche = true,
+                "--byte-frequency" => config.byte_frequency = true,
+                "--init-cost" => config.init_cost = true,
+                "--dyn-dispatch" => config.dyn_dispatch = true,
+                "--bucket-depth" => config.bucket_depth = true,
+                "--serde-hash" => config.serde_hash = true,
+                "--scaling" => config.scaling = true,
+                "--reset-independence" => config.reset_independence = true,
+                "--export-vectors" => config.export_vectors = Some(next_value(&mut args, &arg)),
+                "--verify-vectors" => config.verify_vectors = Some(next_value(&mut args, &arg)),
+                "--high-values" => config.high_values = true,
+                "--save-hashes" => config.save_hashes = Some(SaveHashes {
+                    hasher: next_value(&mut args, &arg),
+                    size: next_value(&mut args, &arg),
+                    count: next_value(&mut args, &arg),
+                }),
+                "--robust-stats" => {
+                    config.robust_stats = true;
+                    continue;
+                }
+                "--save-inputs" => {
+                    config.save_inputs = true;
+                    continue;
+                }
+                "--baseline" => {
+                    config.baseline = Some(next_value(&mut args, &arg));
+                    continue;
+                }
+                "--weights" => {
+                    config.weights = next_value(&mut args, &arg);
+                    continue;
+                }
+                "--memory" => {
+                    config.memory = true;
+                    continue;
+                }
+                "--trim-fraction" => {
+                    config.trim_fraction = next_value(&mut args, &arg);
+                    if !(0.0..0.5).contains(&config.trim_fraction) {
+                        fail("--trim-fraction must be in [0, 0.5)");
+                    }
+                    continue;
+                }
+                "--hasher-groups" => {
+                    let groups: String = next_value(&mut args, &arg);
+                    let hashers = config.hashers.get_or_insert_with(Vec::new);
+                    for group in groups.split(',') {
+                        let (_, names) = HASHER_GROUPS.iter().find(|(name, _)| *name == group)
+                            .unwrap_or_else(|| fail(&format!("Unknown hasher group {:?}", group)));
+                        hashers.extend(names.iter().map(|&name| name.to_owned()));
+                    }
+                    continue;
+                }
+                "--version" => {
+                    println!("hash_bench {}", crate::report::VERSION);
+                    process::exit(0)
+                }
+                _ => fail(&format!("Unknown argument {:?}", arg)),
+            }
+            selected = true;
+        }
+        if config.save_inputs && config.save_hashes.is_none() {
+            fail("--save-inputs requires --save-hashes");
+        }
+
+        if !selected {
+            config.bandwidth = true;
+            config.collisions = true;
+            config.randomness = true;
+        }
+        if config.baseline.is_some() && !config.bandwidth {
+            fail("--baseline requires --bandwidth");
+        }
+        if config.serde_hash && !cfg!(feature = "serde-test") {
+            fail("--serde-hash requires serde-test feature");
+        }
+        config
+    }
+}
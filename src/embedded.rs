@@ -0,0 +1,100 @@
+//! Allocation-free subset of the benchmarks for `no_std` targets (enabled with the `alloc-free` feature).
+//! All buffers are fixed-size arrays, and only bandwidth and randomness on a fixed set of sizes are evaluated.
+//! There is no clock in `core`, so the caller provides a monotonic timer (for example, a cycle counter)
+//! and reports the results on its own.
+
+#![no_std]
+
+#[cfg(feature = "alloc-free")]
+pub use alloc_free::*;
+
+#[cfg(feature = "alloc-free")]
+mod alloc_free {
+    use core::{
+        hash::Hasher,
+        hint::black_box,
+    };
+
+    /// Input sizes, evaluated by `run_all`.
+    pub const SIZES: [usize; 6] = [8, 16, 32, 64, 256, 1024];
+    /// Size of the input buffer, must be at least the largest of `SIZES`.
+    pub const MAX_SIZE: usize = 1024;
+
+    /// Results for one input size.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct EmbeddedResult {
+        pub bytes: usize,
+        /// Timer ticks per hashed byte.
+        pub ticks_per_byte: f64,
+        /// Average number of changed output bits after a single input byte is changed.
+        pub changed_bits: f64,
+    }
+
+    #[inline]
+    fn calc<H: Hasher + Default>(bytes: &[u8]) -> u64 {
+        let mut hasher = H::default();
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    /// Xorshift64 generator for filling the input buffer.
+    fn fill_random(buffer: &mut [u8], state: &mut u64) {
+        for chunk in buffer.chunks_mut(8) {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            chunk.copy_from_slice(&state.to_le_bytes()[..chunk.len()]);
+        }
+    }
+
+    /// Hashes `bytes`-byte input `count` times, and returns the number of timer ticks per byte.
+    pub fn measure_ticks<H: Hasher + Default>(
+        buffer: &[u8; MAX_SIZE],
+        bytes: usize,
+        count: usize,
+        now: &impl Fn() -> u64,
+    ) -> f64 {
+        let input = &buffer[..bytes];
+        let start = now();
+        for _ in 0..count {
+            black_box(calc::<H>(black_box(input)));
+        }
+        now().wrapping_sub(start) as f64 / (count * bytes) as f64
+    }
+
+    /// Same as `test_randomness` in the benchmark: changes each byte of `count` random inputs one at a time,
+    /// and returns the average number of changed output bits.
+    pub fn changed_bits<H: Hasher + Default>(
+        buffer: &mut [u8; MAX_SIZE],
+        bytes: usize,
+        count: usize,
+        seed: u64,
+    ) -> f64 {
+        let mut state = seed | 1;
+        let input = &mut buffer[..bytes];
+        let mut total: u64 = 0;
+        for _ in 0..count {
+            fill_random(input, &mut state);
+            let hash0 = calc::<H>(input);
+            for i in 0..bytes {
+                let b = input[i];
+                input[i] = b.wrapping_add(1);
+                total += u64::from((calc::<H>(input) ^ hash0).count_ones());
+                input[i] = b;
+            }
+        }
+        total as f64 / (bytes * count) as f64
+    }
+
+    /// Evaluates bandwidth and randomness on all `SIZES`, using `count` hashes per size.
+    pub fn run_all<H: Hasher + Default>(count: usize, now: impl Fn() -> u64) -> [EmbeddedResult; SIZES.len()] {
+        let mut buffer = [15_u8; MAX_SIZE];
+        let mut results = [EmbeddedResult::default(); SIZES.len()];
+        for (result, &bytes) in results.iter_mut().zip(&SIZES) {
+            result.bytes = bytes;
+            result.ticks_per_byte = measure_ticks::<H>(&buffer, bytes, count, &now);
+            result.changed_bits = changed_bits::<H>(&mut buffer, bytes, count, 0x9E3779B97F4A7C15);
+        }
+        results
+    }
+}
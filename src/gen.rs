@@ -0,0 +1,275 @@
+use rand::{
+    Rng,
+    distributions::{Alphanumeric, Standard, Distribution},
+};
+
+#[inline]
+pub fn generate_bytes(rng: &mut impl Rng) -> impl Iterator<Item = u8> + '_ {
+    Standard.sample_iter(rng).flat_map(|x: u64| x.to_ne_bytes())
+}
+
+const LETTERS: [u8; 16] = *b"0123456789ABCDEF";
+
+/// Fills iterator with the number in HEX format.
+#[inline]
+pub fn fill_hex<'a>(rev_iter: impl Iterator<Item = &'a mut u8>, mut val: u64) {
+    for byte in rev_iter {
+        *byte = LETTERS[(val & 0xf) as usize];
+        val >>= 4;
+    }
+    assert!(val == 0);
+}
+
+/// Generates `count` strings with random alphanumeric prefix, followed by the fixed `suffix`.
+pub fn random_with_fixed_suffix<const N: usize>(rng: &mut impl Rng, count: usize, suffix: &[u8]) -> Vec<[u8; N]> {
+    assert!(suffix.len() < N);
+    let prefix_len = N - suffix.len();
+    (0..count).map(|_| {
+        let mut arr = [0; N];
+        arr[..prefix_len].iter_mut().for_each(|b| *b = rng.sample(Alphanumeric));
+        arr[prefix_len..].copy_from_slice(suffix);
+        arr
+    }).collect()
+}
+
+/// Generates `count` UNIX timestamps (seconds), evenly spread between 2001 and 2033,
+/// and encodes them as little-endian bytes. High bytes are identical for all values.
+pub fn timestamp_like(count: usize) -> Vec<[u8; 8]> {
+    const START: u64 = 1_000_000_000;
+    const END: u64 = 2_000_000_000;
+    assert!(count > 0 && count as u64 <= END - START);
+    let step = (END - START) / count as u64;
+    (0..count as u64).map(|i| (START + i * step).to_le_bytes()).collect()
+}
+
+/// Generates up to `variant_count` distinct adversarial inputs, consisting of alternating bits:
+/// constant `0x55`, `0xAA`, `0x5A` and `0xA5` patterns, followed by blocks of `0x55` and `0xAA` bytes
+/// (blocks of 1, 2, 4, ... bytes) with all their cyclic shifts,
+/// and a single `0xAA` byte in `0x55` background (and vice versa) at every position.
+pub fn alternating_bits<const N: usize>(variant_count: usize) -> Vec<[u8; N]> {
+    let mut variants: Vec<[u8; N]> = vec![[0x55; N], [0xAA; N], [0x5A; N], [0xA5; N]];
+    let mut block = 1;
+    while block < N {
+        let pattern: [u8; N] = std::array::from_fn(|i| if (i / block) % 2 == 0 { 0x55 } else { 0xAA });
+        for shift in 0..2 * block {
+            let mut arr = pattern;
+            arr.rotate_right(shift % N);
+            variants.push(arr);
+        }
+        block *= 2;
+    }
+    for (background, byte) in [(0x55, 0xAA), (0xAA, 0x55)] {
+        for i in 0..N {
+            let mut arr = [background; N];
+            arr[i] = byte;
+            variants.push(arr);
+        }
+    }
+
+    let mut result = Vec::with_capacity(variants.len().min(variant_count));
+    for arr in variants {
+        if result.len() == variant_count {
+            break;
+        }
+        if !result.contains(&arr) {
+            result.push(arr);
+        }
+    }
+    result
+}
+
+/// Generates lists of distinct `N`-byte keys (zero-padded HEX counters), one list for each power of two
+/// between `min_count` and `max_count`, so that each list has exactly 2^k items.
+pub fn power_of_two_lengths<const N: usize>(min_count: usize, max_count: usize) -> Vec<Vec<[u8; N]>> {
+    assert!(min_count > 0 && min_count <= max_count);
+    assert!(max_count <= 16_usize.saturating_pow(N as u32));
+    let mut count = min_count.next_power_of_two();
+    let mut lists = Vec::new();
+    while count <= max_count {
+        lists.push((0..count as u64).map(|i| {
+            let mut arr = [b'0'; N];
+            fill_hex(arr.iter_mut().rev(), i);
+            arr
+        }).collect());
+        count *= 2;
+    }
+    lists
+}
+
+/// Generates `count` Ethernet frames with a fixed header (random destination and source MACs,
+/// EtherType `0x0800`), followed by `payload_size` random bytes, so that only the payload varies.
+pub fn network_packets(rng: &mut impl Rng, count: usize, payload_size: usize) -> Vec<Vec<u8>> {
+    const HEADER_LEN: usize = 14;
+    let mut header = [0; HEADER_LEN];
+    rng.fill(&mut header[..12]);
+    header[12..].copy_from_slice(&0x0800_u16.to_be_bytes());
+    (0..count).map(|_| {
+        let mut packet = vec![0; HEADER_LEN + payload_size];
+        packet[..HEADER_LEN].copy_from_slice(&header);
+        rng.fill(&mut packet[HEADER_LEN..]);
+        packet
+    }).collect()
+}
+
+/// Generates `count` random strings of 64 lowercase HEX characters, same as SHA256 digests in HEX format.
+pub fn hex_sha256_like(rng: &mut impl Rng, count: usize) -> Vec<[u8; 64]> {
+    (0..count).map(|_| std::array::from_fn(|_| LETTERS[rng.gen_range(0..16)].to_ascii_lowercase())).collect()
+}
+
+/// Generates `count` byte representations of `#[repr(C)] struct { a: u32, b: u8, c: u64 }` (16 bytes),
+/// where bytes 5..8 are alignment padding. Field values are derived from the index, so that they are the same
+/// for all `padding` values. Padding bytes are set to `padding`, or to random values if it is None.
+pub fn struct_with_padding(rng: &mut impl Rng, count: usize, padding: Option<u8>) -> Vec<[u8; 16]> {
+    assert!(count as u64 <= u64::from(u32::MAX) + 1);
+    (0..count as u64).map(|i| {
+        let mut arr = [0; 16];
+        arr[..4].copy_from_slice(&(i as u32).to_le_bytes());
+        arr[4] = (i % 251) as u8;
+        arr[5..8].iter_mut().for_each(|b| *b = padding.unwrap_or_else(|| rng.gen()));
+        arr[8..].copy_from_slice(&i.wrapping_mul(0x9E3779B97F4A7C15).to_le_bytes());
+        arr
+    }).collect()
+}
+
+/// Encodes bytes as standard base64 (with `=` padding).
+fn encode_base64(data: &[u8]) -> Vec<u8> {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = Vec::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0_u32, |acc, (i, &b)| acc | u32::from(b) << (16 - 8 * i));
+        for i in 0..4 {
+            encoded.push(if i <= chunk.len() { TABLE[(n >> (18 - 6 * i)) as usize & 0x3f] } else { b'=' });
+        }
+    }
+    encoded
+}
+
+/// Generates `count` random `decoded_len`-byte strings, encoded as base64.
+/// Uses the random generator in the same way as `random_binary`, so that decoded values are the same for the same seed.
+pub fn base64_encoded(rng: &mut impl Rng, count: usize, decoded_len: usize) -> Vec<Vec<u8>> {
+    random_binary(rng, count, decoded_len).iter().map(|data| encode_base64(data)).collect()
+}
+
+/// Generates `count` random `len`-byte strings.
+pub fn random_binary(rng: &mut impl Rng, count: usize, len: usize) -> Vec<Vec<u8>> {
+    (0..count).map(|_| {
+        let mut data = vec![0; len];
+        rng.fill(&mut data[..]);
+        data
+    }).collect()
+}
+
+/// Generates `data_size` distinct `N`-byte strings with a common random alphanumeric background,
+/// where the two bytes at `var_start` take all values from 0 to `data_size - 1` (big-endian).
+/// Two bytes have only 65536 values, so larger `data_size` panics instead of producing duplicates.
+fn two_byte_variants<const N: usize>(rng: &mut impl Rng, data_size: usize, var_start: usize) -> Vec<[u8; N]> {
+    assert!(data_size <= 1 << 16, "At most 65536 distinct two-byte variants exist, requested {}", data_size);
+    assert!(var_start + 2 <= N);
+    let background: [u8; N] = std::array::from_fn(|_| rng.sample(Alphanumeric));
+    (0..data_size).map(|i| {
+        let mut arr = background;
+        arr[var_start..var_start + 2].copy_from_slice(&(i as u16).to_be_bytes());
+        arr
+    }).collect()
+}
+
+/// Generates `data_size` (at most 65536) strings with a fixed suffix and variable 2-byte prefix.
+pub fn prefix_variant_strings<const N: usize>(rng: &mut impl Rng, data_size: usize) -> Vec<[u8; N]> {
+    two_byte_variants(rng, data_size, 0)
+}
+
+/// Generates `data_size` (at most 65536) strings with a fixed prefix and variable last 2 bytes.
+pub fn suffix_variant_strings<const N: usize>(rng: &mut impl Rng, data_size: usize) -> Vec<[u8; N]> {
+    two_byte_variants(rng, data_size, N - 2)
+}
+
+/// Generates `data_size` (at most 65536) strings, where only 2 bytes at `var_start` are variable.
+pub fn middle_variant_strings<const N: usize>(rng: &mut impl Rng, data_size: usize, var_start: usize)
+    -> Vec<[u8; N]>
+{
+    two_byte_variants(rng, data_size, var_start)
+}
+
+/// Generates `count` distinct Kubernetes-like pod names `<deployment>-<replicaset hash>-<suffix>`
+/// with lowercase alphanumeric 8-character replicaset hash and 5-character suffix.
+/// Deployment name and replicaset hash are the same for all names, only the suffix varies.
+pub fn kubernetes_pod_names(rng: &mut impl Rng, count: usize) -> Vec<Vec<u8>> {
+    const DEPLOYMENT: &[u8] = b"web-frontend";
+    assert!(count as u64 <= 36_u64.pow(5));
+    let mut random_part = |len: usize| -> Vec<u8> {
+        (0..len).map(|_| rng.sample(Alphanumeric).to_ascii_lowercase()).collect()
+    };
+    let mut prefix = DEPLOYMENT.to_vec();
+    prefix.push(b'-');
+    prefix.extend(random_part(8));
+    prefix.push(b'-');
+    let mut suffixes = std::collections::HashSet::with_capacity(count);
+    let mut names = Vec::with_capacity(count);
+    while names.len() < count {
+        let suffix = random_part(5);
+        if suffixes.insert(suffix.clone()) {
+            let mut name = prefix.clone();
+            name.extend(suffix);
+            names.push(name);
+        }
+    }
+    names
+}
+
+/// Common JSON field names in snake_case and camelCase, used by `json_keys`.
+const JSON_FIELDS: &[&str] = &[
+    "id", "uuid", "name", "title", "description", "type", "status", "state", "kind", "category", "tags", "labels",
+    "created_at", "updated_at", "deleted_at", "published_at", "expires_at", "started_at", "finished_at",
+    "last_login_at", "createdAt", "updatedAt", "deletedAt", "publishedAt", "expiresAt", "user_id", "userId",
+    "account_id", "accountId", "owner_id", "ownerId", "parent_id", "parentId", "order_id", "orderId", "product_id",
+    "productId", "customer_id", "customerId", "session_id", "sessionId", "request_id", "requestId", "trace_id",
+    "traceId", "span_id", "spanId", "first_name", "last_name", "firstName", "lastName", "full_name", "fullName",
+    "username", "email", "phone", "phone_number", "phoneNumber", "address", "address_line1", "address_line2", "city",
+    "country", "country_code", "countryCode", "region", "zip", "postal_code", "postalCode", "latitude", "longitude",
+    "timezone", "locale", "language", "currency", "amount", "price", "total", "subtotal", "tax", "discount", "quantity",
+    "count", "limit", "offset", "page", "page_size", "pageSize", "total_count", "totalCount", "next_page", "nextPage",
+    "prev_page", "cursor", "items", "data", "results", "value", "values", "key", "keys", "url", "uri", "href", "link",
+    "path", "method", "headers", "body", "query", "params", "version", "api_version", "apiVersion", "schema_version",
+    "enabled", "disabled", "active", "is_active", "isActive", "is_deleted", "isDeleted", "visible", "hidden",
+    "verified", "is_verified", "isVerified", "admin", "is_admin", "isAdmin", "role", "roles", "permissions", "scope",
+    "scopes", "token", "access_token", "accessToken", "refresh_token", "refreshToken", "expires_in", "expiresIn",
+    "token_type", "password", "password_hash", "salt", "hash", "checksum", "signature", "algorithm", "content",
+    "content_type", "contentType", "mime_type", "size", "length", "width", "height", "duration", "timestamp", "date",
+    "time", "start", "end", "start_date", "end_date", "startDate", "endDate", "year", "month", "day", "hour", "minute",
+    "error", "errors", "message", "code", "reason", "details", "warning", "warnings", "level", "severity", "source",
+    "target", "host", "port", "ip", "ip_address", "ipAddress", "user_agent", "userAgent", "referrer", "platform",
+    "device", "os", "browser", "score", "rating", "rank", "priority", "weight", "color", "image", "image_url",
+    "imageUrl", "thumbnail", "avatar", "avatar_url", "avatarUrl", "file_name", "fileName", "file_size", "metadata",
+    "meta", "attributes", "properties", "settings", "config", "options", "extra", "notes", "comment", "comments",
+];
+
+/// Generates `count` distinct JSON object keys: cycles through common field names,
+/// and appends a numeric suffix starting from the second cycle (`created_at`, ..., `created_at1`, ...).
+pub fn json_keys(count: usize) -> Vec<Vec<u8>> {
+    (0..count).map(|i| {
+        let mut key = JSON_FIELDS[i % JSON_FIELDS.len()].as_bytes().to_vec();
+        let cycle = i / JSON_FIELDS.len();
+        if cycle > 0 {
+            key.extend_from_slice(cycle.to_string().as_bytes());
+        }
+        key
+    }).collect()
+}
+
+/// Generates `count` `N`-byte keys `salt || body` (such as `tenant_id || entity_id`), where the random
+/// `N - salt_len`-byte body is the same for all keys, and each key has a distinct random `salt_len`-byte salt.
+pub fn salted_keys<const N: usize>(rng: &mut impl Rng, salt_len: usize, count: usize) -> Vec<[u8; N]> {
+    assert!(salt_len < N && (salt_len >= 8 || count as u64 <= 1 << (8 * salt_len)));
+    let mut body = [0; N];
+    rng.fill(&mut body[salt_len..]);
+    let mut salts = std::collections::HashSet::with_capacity(count);
+    let mut keys = Vec::with_capacity(count);
+    while keys.len() < count {
+        let mut key = body;
+        rng.fill(&mut key[..salt_len]);
+        if salts.insert(key[..salt_len].to_vec()) {
+            keys.push(key);
+        }
+    }
+    keys
+}
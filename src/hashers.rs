@@ -0,0 +1,201 @@
+use std::hash::Hasher;
+use highway::HighwayHash;
+use fasthash::HasherExt;
+
+/// Hasher that can produce 128-bit output in addition to the standard 64-bit `finish`.
+pub trait Hasher128: Hasher + Default {
+    fn finish128(&self) -> u128;
+}
+
+impl Hasher128 for metrohash::MetroHash128 {
+    fn finish128(&self) -> u128 {
+        let (hi, lo) = metrohash::MetroHash128::finish128(self);
+        (u128::from(hi) << 64) | u128::from(lo)
+    }
+}
+
+impl Hasher128 for highway::HighwayHasher {
+    fn finish128(&self) -> u128 {
+        let [lo, hi] = self.clone().finalize128();
+        (u128::from(hi) << 64) | u128::from(lo)
+    }
+}
+
+macro_rules! impl_fasthash128 {
+    ($($hasher:ty),*) => {
+        $(
+            impl Hasher128 for $hasher {
+                fn finish128(&self) -> u128 {
+                    self.finish_ext()
+                }
+            }
+        )*
+    };
+}
+
+impl_fasthash128!(
+    fasthash::murmur3::Hasher128_x86,
+    fasthash::murmur3::Hasher128_x64,
+    fasthash::SpookyHasherExt,
+    fasthash::city::Hasher128,
+    fasthash::FarmHasherExt,
+    fasthash::T1haHasherExt
+);
+
+/// XXH32 with zero seed, 32-bit output is zero-extended to 64 bits.
+pub struct Xxh32Wrapper(xxhash_rust::xxh32::Xxh32);
+
+impl Default for Xxh32Wrapper {
+    fn default() -> Self {
+        Self(xxhash_rust::xxh32::Xxh32::new(0))
+    }
+}
+
+impl Hasher for Xxh32Wrapper {
+    #[inline]
+    fn finish(&self) -> u64 {
+        u64::from(self.0.digest())
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+}
+
+/// t1ha0 variant for 32-bit CPUs (`t1ha0_32le`) with zero seed. It is not wrapped by fasthash,
+/// so it is called through fasthash-sys and, similarly to fasthash hashers, input is buffered until `finish`.
+#[derive(Default, Clone)]
+pub struct T1ha0_32Le(Vec<u8>);
+
+impl Hasher for T1ha0_32Le {
+    #[inline]
+    fn finish(&self) -> u64 {
+        unsafe { fasthash_sys::t1ha0_32le(self.0.as_ptr().cast(), self.0.len(), 0) }
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+}
+
+/// SeaHasher with fixed custom seeds (hexadecimal digits of pi) instead of the crate default.
+pub struct SeaHashFixed(seahash::SeaHasher);
+
+impl Default for SeaHashFixed {
+    fn default() -> Self {
+        Self(seahash::SeaHasher::with_seeds(
+            0x243f6a8885a308d3,
+            0x13198a2e03707344,
+            0xa4093822299f31d0,
+            0x082efa98ec4e6c89,
+        ))
+    }
+}
+
+impl Hasher for SeaHashFixed {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+}
+
+/// Hasher that can be constructed from a 64-bit seed.
+pub trait SeedableHasher: Hasher + Default {
+    fn with_seed(seed: u64) -> Self;
+}
+
+impl SeedableHasher for siphasher::sip::SipHasher13 {
+    fn with_seed(seed: u64) -> Self {
+        Self::new_with_keys(seed, 0)
+    }
+}
+
+impl SeedableHasher for siphasher::sip::SipHasher24 {
+    fn with_seed(seed: u64) -> Self {
+        Self::new_with_keys(seed, 0)
+    }
+}
+
+impl SeedableHasher for ahash::AHasher {
+    fn with_seed(seed: u64) -> Self {
+        use std::hash::BuildHasher;
+        ahash::RandomState::with_seeds(seed, 0, 0, 0).build_hasher()
+    }
+}
+
+impl SeedableHasher for seahash::SeaHasher {
+    fn with_seed(seed: u64) -> Self {
+        // Remaining seeds are the same as in `SeaHasher::default`.
+        Self::with_seeds(seed, 0xb480a793d8e6c86c, 0x6fe2e5aaf078ebc9, 0x14f994a4c5259381)
+    }
+}
+
+impl SeedableHasher for highway::HighwayHasher {
+    fn with_seed(seed: u64) -> Self {
+        Self::new(highway::Key([seed, 0, 0, 0]))
+    }
+}
+
+impl SeedableHasher for fnv::FnvHasher {
+    fn with_seed(seed: u64) -> Self {
+        Self::with_key(seed)
+    }
+}
+
+impl SeedableHasher for xxhash_rust::xxh64::Xxh64 {
+    fn with_seed(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+macro_rules! impl_seedable {
+    ($($hasher:ty),*) => {
+        $(
+            impl SeedableHasher for $hasher {
+                fn with_seed(seed: u64) -> Self {
+                    <$hasher>::with_seed(seed)
+                }
+            }
+        )*
+    };
+}
+
+impl_seedable!(
+    metrohash::MetroHash64,
+    metrohash::MetroHash128,
+    wyhash::WyHash,
+    wyhash2::WyHash
+);
+
+/// fasthash hashers with seeds of various types, 64-bit seed is converted with `as`.
+macro_rules! impl_fasthash_seedable {
+    ($($hasher:ty => $seed:ty),*) => {
+        $(
+            impl SeedableHasher for $hasher {
+                fn with_seed(seed: u64) -> Self {
+                    <$hasher as fasthash::FastHasher>::with_seed(seed as $seed)
+                }
+            }
+        )*
+    };
+}
+
+impl_fasthash_seedable!(
+    fasthash::T1haHasher => u64,
+    fasthash::murmur2::Hasher64_x64 => u64,
+    fasthash::murmur3::Hasher32 => u32,
+    fasthash::murmur3::Hasher128_x86 => u32,
+    fasthash::murmur3::Hasher128_x64 => u32,
+    fasthash::CityHasher => u64,
+    fasthash::SpookyHasher => u64,
+    fasthash::FarmHasher => u64,
+    fasthash::XXHasher => u64,
+    fasthash::Lookup3Hasher => u32
+);
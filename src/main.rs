@@ -1,32 +1,43 @@
+mod config;
+mod gen;
+mod hashers;
+mod platform;
+mod report;
+mod stats;
+mod vectors;
+
 use std::{
     fs,
     io::{self, Write},
     time::Instant,
     hash::Hasher,
-    path::Path,
+    path::{Path, PathBuf},
     hint::black_box,
 };
 use rand::{
     Rng, SeedableRng,
-    distributions::{Alphanumeric, Standard, Distribution},
+    distributions::Alphanumeric,
 };
+use config::{Config, SizeRange};
+use gen::{generate_bytes, fill_hex};
+use hashers::{Hasher128, SeedableHasher};
+use stats::mean_variance;
 
-/// Returns mean and variance together.
-pub fn mean_variance(a: &[f64]) -> (f64, f64) {
-    let n = a.len();
-    assert!(n > 1);
-    let mean = a.iter().sum::<f64>() / n as f64;
-    let var = a.iter().fold(0.0, |acc, x| {
-            let diff = x - mean;
-            acc + diff * diff
-        }) / (n - 1) as f64;
-    (mean, var)
-}
+#[cfg(not(any(feature = "ahash-runtime-rng", feature = "ahash-ctrng")))]
+compile_error!("Either ahash-runtime-rng or ahash-ctrng feature must be enabled");
 
-#[inline]
-fn generate_bytes(rng: &mut impl Rng) -> impl Iterator<Item = u8> + '_ {
-    Standard.sample_iter(rng).flat_map(|x: u64| x.to_ne_bytes())
-}
+/// Name of the AHasher benchmark, depending on how its seeds are generated.
+const AHASH_NAME: &str = if cfg!(feature = "ahash-runtime-rng") { "ahash" } else { "ahash_ctrng" };
+
+/// t1ha0 implementation, selected by `build.rs` based on the target CPU features.
+#[cfg(t1ha_native = "avx2")]
+type T1haNative = fasthash::t1ha0::avx2::Hasher64;
+#[cfg(t1ha_native = "avx")]
+type T1haNative = fasthash::t1ha0::avx::Hasher64;
+#[cfg(t1ha_native = "aes")]
+type T1haNative = fasthash::t1ha0::aes::Hasher64;
+#[cfg(t1ha_native = "portable")]
+type T1haNative = fasthash::t1ha0::Hasher64_64;
 
 #[inline]
 fn calc<H: Hasher + Default>(bytes: &[u8]) -> u64 {
@@ -35,49 +46,99 @@ fn calc<H: Hasher + Default>(bytes: &[u8]) -> u64 {
     hasher.finish()
 }
 
+#[inline]
+fn calc128<H: Hasher128>(bytes: &[u8]) -> u128 {
+    let mut hasher = H::default();
+    hasher.write(bytes);
+    hasher.finish128()
+}
+
+/// Hashes the buffer `count` times in a row, and returns bandwidth (Mb/s) for each of `iters` iterations.
+fn measure_bandwidth<H>(buffer: &[u8], count: usize, iters: usize) -> Vec<f64>
+where H: Hasher + Default,
+{
+    let mut values = Vec::with_capacity(iters);
+    for _ in 0..iters {
+        let timer = Instant::now();
+        for _ in 0..count {
+            black_box(calc::<H>(black_box(buffer)));
+        }
+        let runtime = timer.elapsed();
+        let bandwidth = 1e-6 * (count * buffer.len()) as f64 / runtime.as_secs_f64();
+        values.push(bandwidth);
+    }
+    values
+}
+
+/// Returns location and spread of bandwidth measurements:
+/// mean and standard deviation by default, median and median absolute deviation if `robust` is true.
+fn summarize(values: &[f64], robust: bool) -> (f64, f64) {
+    if robust {
+        (stats::median(values), stats::median_absolute_deviation(values))
+    } else {
+        let (mean, var) = mean_variance(values);
+        (mean, var.sqrt())
+    }
+}
+
+/// Measures bandwidth on `bytes`-byte input, summarized according to `robust_stats` and `trim_fraction`.
+/// Truncated mean replaces the mean only if robust statistics are not used.
+/// Also reports 50th, 90th, 95th and 99th percentiles of the average hash latency (ns) across iterations.
 fn evaluate<H>(
     name: &str,
+    bits: u32,
     bytes: usize,
     count: usize,
     iters: usize,
+    config: &Config,
     writer: &mut impl Write,
 ) -> io::Result<()>
 where H: Hasher + Default,
 {
     eprintln!("Running {} on {} bytes", name, bytes);
     let buffer = vec![15; bytes];
-    let mut values = Vec::with_capacity(iters);
-    for _ in 0..iters {
-        let timer = Instant::now();
-        for _ in 0..count {
-            black_box(calc::<H>(black_box(&buffer)));
-        }
-        let runtime = timer.elapsed();
-        let bandwidth = 1e-6 * (count * bytes) as f64 / runtime.as_secs_f64();
-        values.push(bandwidth);
+    let values = measure_bandwidth::<H>(&buffer, count, iters);
+    let robust = config.robust_stats;
+    let (mut mean, sd) = summarize(&values, robust);
+    let trim_fraction = if robust { 0.0 } else { config.trim_fraction };
+    if trim_fraction > 0.0 {
+        mean = stats::trim_mean(&values, trim_fraction);
     }
-    let (mean, var) = mean_variance(&values);
-    let sd = var.sqrt();
+    let mad = stats::median_absolute_deviation(&values);
+    // Average latency of a single hash (ns) in each iteration.
+    let mut latencies: Vec<f64> = values.iter().map(|&bandwidth| 1e3 * bytes as f64 / bandwidth).collect();
+    latencies.sort_unstable_by(f64::total_cmp);
+    let [p50, p90, p95, p99] = [50.0, 90.0, 95.0, 99.0].map(|p| stats::percentile(&latencies, p));
     eprintln!("    -> {:5.0}±{:5.0} Mb/s", mean, sd);
-    writeln!(writer, "{}\t{}\t{}\t{}\t{:.10}\t{:.10}", name, bytes, count, iters, mean, sd)?;
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.10}\t{}\t{}\t{:.4}\t{:.4}\t{:.4}\t{:.4}",
+        name, bits, bytes, count, iters, mean, sd, mad, robust, trim_fraction, p50, p90, p95, p99)?;
     Ok(())
 }
 
-/// Fills iterator with the number in HEX format.
-#[inline]
-fn fill_hex<'a>(rev_iter: impl Iterator<Item = &'a mut u8>, mut val: u64) {
-    const LETTERS: [u8; 16] = *b"0123456789ABCDEF";
-    for byte in rev_iter {
-        *byte = LETTERS[(val & 0xf) as usize];
-        val >>= 4;
+/// Runs `evaluate` for each input size in the range.
+fn evaluate_all_sizes<H>(
+    name: &str,
+    bits: u32,
+    range: &SizeRange,
+    count: usize,
+    iters: usize,
+    config: &Config,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    for bytes in (range.min..=range.max).step_by(range.step) {
+        evaluate::<H>(name, bits, bytes, count, iters, config, writer)?;
     }
-    assert!(val == 0);
+    Ok(())
 }
 
 /// Check collisions on `count` strings with variable infix at `affix_range` and
 /// identical remaining alphanumeric string.
+/// Also reports 95% Clopper-Pearson confidence interval for the collision rate (collisions / count).
 fn test_collisions<H>(
     name: &str,
+    bits: u32,
     rng: &mut impl Rng,
     count: usize,
     length: usize,
@@ -97,14 +158,34 @@ where H: Hasher + Default,
         fill_hex(buffer[affix_range.clone()].iter_mut().rev(), val);
         collisions += u64::from(!set.insert(calc::<H>(&buffer)));
     }
-    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{}", name, length, affix_range.start, affix_range.end,
-        collisions, count)?;
+    let (ci_low, ci_high) = stats::clopper_pearson(collisions, count as u64, 0.05);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{:.6e}\t{:.6e}", name, bits, length, affix_range.start,
+        affix_range.end, collisions, count, ci_low, ci_high)?;
     eprintln!("    -> {:.2} s, {} collisions / {}", timer.elapsed().as_secs_f64(), collisions, count);
     Ok(())
 }
 
+/// Changes each byte of the buffer one at a time, and counts how many output bits are changed.
+/// Buffer is restored afterwards.
+#[inline]
+fn count_changed_bits<H>(buffer: &mut [u8], matches_count: &mut [u64; 65])
+where H: Hasher + Default,
+{
+    let hash0 = calc::<H>(buffer);
+    for i in 0..buffer.len() {
+        let b = *unsafe { buffer.get_unchecked(i) };
+        unsafe { *buffer.get_unchecked_mut(i) = b.wrapping_add(1) };
+        let hash = calc::<H>(buffer);
+        unsafe { *buffer.get_unchecked_mut(i) = b };
+        matches_count[(hash0 ^ hash).count_ones() as usize] += 1;
+    }
+}
+
+/// Measures the average number of changed output bits after a single input byte is changed.
+/// Randomness equals 1 if exactly half of `bits` output bits change on average.
 fn test_randomness<H>(
     name: &str,
+    bits: u32,
     rng: &mut impl Rng,
     count: usize,
     length: usize,
@@ -119,138 +200,3314 @@ where H: Hasher + Default,
     let mut matches_count = [0_u64; 65];
     for _ in 0..count {
         buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
-        let hash0 = calc::<H>(&buffer);
-        for i in 0..length {
-            let b = *unsafe { buffer.get_unchecked(i) };
-            unsafe { *buffer.get_unchecked_mut(i) = b.wrapping_add(1) };
-            let hash = calc::<H>(&buffer);
-            unsafe { *buffer.get_unchecked_mut(i) = b };
-            matches_count[(hash0 ^ hash).count_ones() as usize] += 1;
-        }
+        count_changed_bits::<H>(&mut buffer, &mut matches_count);
     }
     let average_change = matches_count.into_iter().enumerate()
         .map(|(i, c)| (i as u64 * c) as f64)
         .sum::<f64>()
         / (length * count) as f64;
-    let randomness01 = 1.0 - (average_change / 32.0 - 1.0).abs();
-    writeln!(writer, "{}\t{}\t{:.7}\t{:.10}", name, length, average_change, randomness01)?;
+    let randomness01 = 1.0 - (2.0 * average_change / f64::from(bits) - 1.0).abs();
+    writeln!(writer, "{}\t{}\t{}\t{:.7}\t{:.10}", name, bits, length, average_change, randomness01)?;
     eprintln!("    -> {:.2} s, {:.3} bits changed on average, randomness {:.5}", timer.elapsed().as_secs_f64(),
         average_change, randomness01);
     Ok(())
 }
 
-fn test_hasher<H>(
+/// Counts hash collisions across the given inputs.
+fn count_collisions<H>(inputs: &[impl AsRef<[u8]>]) -> u64
+where H: Hasher + Default,
+{
+    let mut collisions = 0;
+    let mut set: std::collections::HashSet<u64, ahash::RandomState> = Default::default();
+    set.reserve(inputs.len());
+    for input in inputs {
+        collisions += u64::from(!set.insert(calc::<H>(input.as_ref())));
+    }
+    collisions
+}
+
+/// Check collisions on `N`-byte strings with a common file extension and variable random prefix.
+/// Hashers, that mostly rely on the last bytes, should produce more collisions.
+fn test_suffix_collisions<H, const N: usize>(
     name: &str,
-    mut rng: impl Rng,
-    writer1: Option<&mut io::BufWriter<fs::File>>,
-    writer2: Option<&mut io::BufWriter<fs::File>>,
-    writer3: Option<&mut io::BufWriter<fs::File>>,
+    rng: &mut impl Rng,
+    count: usize,
+    writer: &mut impl Write,
 ) -> io::Result<()>
 where H: Hasher + Default,
 {
-    if let Some(writer1) = writer1 {
-        const ITERS: usize = 1024;
-        evaluate::<H>(name, 4, 2_usize.pow(18), ITERS, writer1)?;
-        evaluate::<H>(name, 8, 2_usize.pow(18), ITERS, writer1)?;
-        evaluate::<H>(name, 12, 2_usize.pow(18), ITERS, writer1)?;
-        evaluate::<H>(name, 16, 2_usize.pow(18), ITERS, writer1)?;
-        evaluate::<H>(name, 32, 2_usize.pow(17), ITERS, writer1)?;
-        evaluate::<H>(name, 64, 2_usize.pow(16), ITERS, writer1)?;
-        evaluate::<H>(name, 128, 2_usize.pow(16), ITERS, writer1)?;
-        evaluate::<H>(name, 256, 2_usize.pow(15), ITERS, writer1)?;
-        evaluate::<H>(name, 512, 2_usize.pow(15), ITERS, writer1)?;
-        evaluate::<H>(name, 1024, 2_usize.pow(14), ITERS, writer1)?;
-        evaluate::<H>(name, 2048, 2_usize.pow(14), ITERS, writer1)?;
-        evaluate::<H>(name, 4096, 2_usize.pow(14), ITERS, writer1)?;
-    }
-
-    if let Some(writer2) = writer2 {
-        let count = 2_usize.pow(24);
-        let affix = 6;
-        for size in (8..=32).step_by(2) {
-            // test_collisions::<H>(name, &mut rng, count, size, 0..affix, writer2)?;
-            // test_collisions::<H>(name, &mut rng, count, size, 8..8 + affix, writer2)?;
-            test_collisions::<H>(name, &mut rng, count, size + affix, size..size + affix, writer2)?;
-        }
+    for suffix in ["json", "log", "txt"] {
+        let suffix = format!(".{}", suffix);
+        eprintln!("Testing {} for collisions, {}-string with fixed suffix {:?}", name, N, suffix);
+        let timer = Instant::now();
+        let inputs = gen::random_with_fixed_suffix::<N>(rng, count, suffix.as_bytes());
+        let collisions = count_collisions::<H>(&inputs);
+        writeln!(writer, "{}\t{}\t{}\t{}\t{}", name, N, suffix, collisions, count)?;
+        eprintln!("    -> {:.2} s, {} collisions / {}", timer.elapsed().as_secs_f64(), collisions, count);
     }
+    Ok(())
+}
 
-    if let Some(writer3) = writer3 {
-        let count = 2_usize.pow(22);
-        for &size in &[8, 12, 16, 20, 24, 28, 32] {
-            test_randomness::<H>(name, &mut rng, count, size, writer3)?;
+/// Checks that the higher and lower 64-bit halves of a 128-bit hash are not correlated.
+fn test_128bit_independence<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher128,
+{
+    eprintln!("Testing {} for independence of 128-bit halves, length {}", name, length);
+    let timer = Instant::now();
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    let mut his = Vec::with_capacity(count);
+    let mut los = Vec::with_capacity(count);
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        let hash = calc128::<H>(&buffer);
+        his.push((hash >> 64) as u64 as f64);
+        los.push(hash as u64 as f64);
+    }
+    let pearson = stats::pearson(&his, &los);
+    writeln!(writer, "{}\t{}\t{:.10}", name, length, pearson)?;
+    eprintln!("    -> {:.2} s, correlation {:.5}", timer.elapsed().as_secs_f64(), pearson);
+    Ok(())
+}
+
+/// Check that a zero byte in the middle of the input does not truncate it:
+/// 32-byte inputs with a single zero byte at different positions should all have different hashes.
+fn test_null_byte_handling<H>(
+    name: &str,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const POSITIONS: [usize; 5] = [0, 8, 16, 24, 31];
+    eprintln!("Testing {} for null byte handling", name);
+    let mut hashes = Vec::with_capacity(POSITIONS.len());
+    for &pos in &POSITIONS {
+        let mut buffer: Vec<u8> = (1..=32).collect();
+        buffer[pos] = 0;
+        let hash = calc::<H>(&buffer);
+        writeln!(writer, "{}\t{}\t{:016x}", name, pos, hash)?;
+        hashes.push(hash);
+    }
+    hashes.sort_unstable();
+    hashes.dedup();
+    eprintln!("    -> {} distinct hashes / {}", hashes.len(), POSITIONS.len());
+    if hashes.len() < POSITIONS.len() {
+        eprintln!("    WARNING: {} produces equal hashes for different null byte positions", name);
+    }
+    Ok(())
+}
+
+/// Check collisions on a fixed set of inputs, produced by one of the generators.
+fn test_dataset_collisions<H>(
+    name: &str,
+    dataset: &str,
+    inputs: &[impl AsRef<[u8]>],
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for collisions on {} dataset", name, dataset);
+    let timer = Instant::now();
+    let collisions = count_collisions::<H>(inputs);
+    writeln!(writer, "{}\t{}\t{}\t{}", name, dataset, collisions, inputs.len())?;
+    eprintln!("    -> {:.2} s, {} collisions / {}", timer.elapsed().as_secs_f64(), collisions, inputs.len());
+    Ok(())
+}
+
+/// Same as `test_randomness`, but on a fixed set of inputs, produced by one of the generators.
+fn test_dataset_randomness<H>(
+    name: &str,
+    bits: u32,
+    dataset: &str,
+    inputs: &[impl AsRef<[u8]>],
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for randomness on {} dataset", name, dataset);
+    let timer = Instant::now();
+    let mut buffer = Vec::new();
+    let mut matches_count = [0_u64; 65];
+    let mut total_bytes = 0;
+    for input in inputs {
+        buffer.clear();
+        buffer.extend_from_slice(input.as_ref());
+        count_changed_bits::<H>(&mut buffer, &mut matches_count);
+        total_bytes += buffer.len();
+    }
+    let average_change = matches_count.into_iter().enumerate()
+        .map(|(i, c)| (i as u64 * c) as f64)
+        .sum::<f64>()
+        / total_bytes as f64;
+    let randomness01 = 1.0 - (2.0 * average_change / f64::from(bits) - 1.0).abs();
+    writeln!(writer, "{}\t{}\t{}\t{:.7}\t{:.10}", name, bits, dataset, average_change, randomness01)?;
+    eprintln!("    -> {:.2} s, {:.3} bits changed on average, randomness {:.5}", timer.elapsed().as_secs_f64(),
+        average_change, randomness01);
+    Ok(())
+}
+
+/// Check that writing input in several chunks produces the same hash as writing it in a single call.
+fn test_streaming_consistency<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    chunk_sizes: &[usize],
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for streaming consistency, length {}", name, length);
+    let timer = Instant::now();
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    let mut mismatches = vec![0_u64; chunk_sizes.len()];
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        let hash0 = calc::<H>(&buffer);
+        for (&chunk_size, mismatch) in chunk_sizes.iter().zip(mismatches.iter_mut()) {
+            let mut hasher = H::default();
+            buffer.chunks(chunk_size).for_each(|chunk| hasher.write(chunk));
+            *mismatch += u64::from(hasher.finish() != hash0);
         }
     }
-    eprintln!();
+    for (chunk_size, mismatch) in chunk_sizes.iter().zip(&mismatches) {
+        writeln!(writer, "{}\t{}\t{}\t{}", name, length, chunk_size, mismatch)?;
+    }
+    eprintln!("    -> {:.2} s, mismatches {:?} / {}", timer.elapsed().as_secs_f64(), mismatches, count);
     Ok(())
 }
 
-fn main() {
-    let out_dir = Path::new("out");
-    if !out_dir.exists() {
-        fs::create_dir(out_dir).unwrap();
+/// Measures bandwidth for various numbers of back-to-back hashes in one timing iteration:
+/// from a single cold invocation to pipelined throughput.
+fn evaluate_throughput_vs_count<H>(
+    name: &str,
+    bytes: usize,
+    counts: &[usize],
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    let buffer = vec![15; bytes];
+    for &count in counts {
+        eprintln!("Running {} on {} bytes, {} hashes per iteration", name, bytes, count);
+        let values = measure_bandwidth::<H>(&buffer, count, iters);
+        let (mean, sd) = summarize(&values, robust);
+        eprintln!("    -> {:5.0}±{:5.0} Mb/s", mean, sd);
+        writeln!(writer, "{}\t{}\t{}\t{}\t{:.10}\t{:.10}", name, bytes, count, iters, mean, sd)?;
+    }
+    Ok(())
+}
+
+/// Check whether the hasher depends on the byte order: compares hashes of random strings and their reverse.
+/// Palindromes themselves are skipped, as they trivially produce the same hash.
+/// Any equality suggests that the hasher ignores byte order; the expected equality rate is 0.
+fn test_palindrome_hashing<H>(
+    name: &str,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const COUNT: usize = 1000;
+    eprintln!("Testing {} for byte order dependence", name);
+    let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0);
+    for length in [4, 8, 16, 32] {
+        let mut buffer = vec![0; length];
+        let mut reversed = vec![0; length];
+        let mut equal = 0;
+        let mut total = 0;
+        while total < COUNT {
+            rng.fill(&mut buffer[..]);
+            reversed.copy_from_slice(&buffer);
+            reversed.reverse();
+            if buffer != reversed {
+                equal += usize::from(calc::<H>(&buffer) == calc::<H>(&reversed));
+                total += 1;
+            }
+        }
+        let rate = equal as f64 / total as f64;
+        writeln!(writer, "{}\t{}\t{}\t{}\t{:.6}", name, length, equal, total, rate)?;
+        eprintln!("    -> length {}: {} equal / {}", length, equal, total);
     }
+    Ok(())
+}
 
-    let calc_bandwidth = true;
-    let calc_collisions = true;
-    let calc_randomness = true;
+/// Measures bandwidth on input sizes, for which SIMD hashers typically use different code paths:
+/// - 1-15 bytes: shorter than a 128-bit register, processed by scalar fallback,
+/// - 16-31 bytes: at least one full 128-bit (SSE2) register,
+/// - 32-63 bytes: at least one full 256-bit (AVX2) register,
+/// - 64-127 bytes: two or more AVX2 registers, processed in several passes.
+///
+/// Each range is probed at its start, middle and end, so that the end also shows the cost of tail handling.
+fn evaluate_simd_comparison<H>(
+    name: &str,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const RANGES: [(&str, [usize; 3]); 4] = [
+        ("1-15", [1, 8, 15]),
+        ("16-31", [16, 24, 31]),
+        ("32-63", [32, 48, 63]),
+        ("64-127", [64, 96, 127]),
+    ];
+    const COUNT: usize = 2_usize.pow(16);
+    const ITERS: usize = 256;
+    for (range, sizes) in RANGES {
+        for bytes in sizes {
+            eprintln!("Running {} on {} bytes (range {})", name, bytes, range);
+            let values = measure_bandwidth::<H>(&vec![15; bytes], COUNT, ITERS);
+            let (mean, sd) = summarize(&values, robust);
+            eprintln!("    -> {:5.0}±{:5.0} Mb/s", mean, sd);
+            writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}", name, range, bytes, COUNT, ITERS, mean, sd)?;
+        }
+    }
+    Ok(())
+}
 
-    let mut writer1 = if calc_bandwidth {
-        let mut writer = io::BufWriter::new(fs::File::create(out_dir.join("bandwidth.csv")).unwrap());
-        writeln!(writer, "hasher\tbytes\tcount\titers\tbandwidth_mean\tbandwidth_sd").unwrap();
-        Some(writer)
-    } else {
-        None
-    };
-    let mut writer2 = if calc_collisions {
-        let mut writer = io::BufWriter::new(fs::File::create(out_dir.join("collisions.csv")).unwrap());
-        writeln!(writer, "hasher\tbytes\tvar_start\tvar_end\tcollisions\tcount").unwrap();
-        Some(writer)
+/// Saves raw hashes (u64, little-endian) of `count` random `length`-byte inputs to `hashes_<hasher>_<length>.bin`,
+/// so that they can be analyzed by external statistical test suites.
+/// If `save_inputs` is true, concatenated inputs are saved to `inputs_<hasher>_<length>.bin`.
+fn save_hashes<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    out_dir: &Path,
+    save_inputs: bool,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Saving {} hashes of {} on {} bytes", count, name, length);
+    let mut hashes_writer = io::BufWriter::new(
+        fs::File::create(out_dir.join(format!("hashes_{}_{}.bin", name, length)))?);
+    let mut inputs_writer = if save_inputs {
+        Some(io::BufWriter::new(fs::File::create(out_dir.join(format!("inputs_{}_{}.bin", name, length)))?))
     } else {
         None
     };
-    let mut writer3 = if calc_randomness {
-        let mut writer = io::BufWriter::new(fs::File::create(out_dir.join("randomness.csv")).unwrap());
-        writeln!(writer, "hasher\tbytes\tchanged_bits\trandomness").unwrap();
-        Some(writer)
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        hashes_writer.write_all(&calc::<H>(&buffer).to_le_bytes())?;
+        if let Some(writer) = inputs_writer.as_mut() {
+            writer.write_all(&buffer)?;
+        }
+    }
+    hashes_writer.flush()?;
+    if let Some(mut writer) = inputs_writer {
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+/// For each random input, changes bytes one by one starting from the beginning,
+/// until the hash differs from the original hash by more than a quarter of `bits` (16 bits for 64-bit hashers).
+/// Reports mean and median number of changed bytes over inputs for which this happened.
+fn test_avalanche_convergence<H>(
+    name: &str,
+    bits: u32,
+    rng: &mut impl Rng,
+    count: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const LENGTH: usize = 64;
+    eprintln!("Testing {} for avalanche convergence, length {}", name, LENGTH);
+    let timer = Instant::now();
+    let mut buffer = vec![0; LENGTH];
+    let mut positions = Vec::with_capacity(count);
+    for _ in 0..count {
+        rng.fill(&mut buffer[..]);
+        let hash0 = calc::<H>(&buffer);
+        for i in 0..LENGTH {
+            buffer[i] ^= rng.gen_range(1..=u8::MAX);
+            if (calc::<H>(&buffer) ^ hash0).count_ones() > bits / 4 {
+                positions.push((i + 1) as f64);
+                break;
+            }
+        }
+    }
+    let not_converged = count - positions.len();
+    let (mean, median) = if positions.is_empty() {
+        (f64::NAN, f64::NAN)
     } else {
-        None
+        (positions.iter().sum::<f64>() / positions.len() as f64, stats::median(&positions))
     };
+    writeln!(writer, "{}\t{}\t{:.6}\t{}\t{}\t{}", name, LENGTH, mean, median, not_converged, count)?;
+    eprintln!("    -> {:.2} s, {:.3} bytes on average, median {}, {} not converged", timer.elapsed().as_secs_f64(),
+        mean, median, not_converged);
+    Ok(())
+}
 
-    let rng = rand_xoshiro::Xoshiro256PlusPlus::from_entropy();
-    test_hasher::<siphasher::sip::SipHasher13>("sip13", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<siphasher::sip::SipHasher24>("sip24", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<ahash::AHasher>("ahash", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<seahash::SeaHasher>("seahash", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<metrohash::MetroHash64>("metro64", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<metrohash::MetroHash128>("metro128", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<rustc_hash::FxHasher>("fxhash", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<wyhash::WyHash>("wyhash", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<wyhash2::WyHash>("wyhash2", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<xxhash_rust::xxh64::Xxh64>("xxhash64", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<highway::HighwayHasher>("highway", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<fasthash::T1haHasher>("t1ha", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<fnv::FnvHasher>("fnv", rng.clone(),
-        writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<fasthash::murmur2::Hasher64_x64>("murmur2",
-        rng.clone(), writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<fasthash::murmur3::Hasher128_x64>("murmur3",
-            rng.clone(), writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<fasthash::CityHasher>("city",
-        rng.clone(), writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<fasthash::SpookyHasher>("spooky",
-        rng.clone(), writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
-    test_hasher::<fasthash::FarmHasher>("farm",
-        rng.clone(), writer1.as_mut(), writer2.as_mut(), writer3.as_mut()).unwrap();
+/// Hashes the buffer `depth` times, seeding each hasher with the previous hash value, and
+/// measures time (ns) per chain. Comparing different depths separates setup overhead from hashing itself.
+fn evaluate_chained<H>(
+    name: &str,
+    bytes: usize,
+    depth: usize,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: SeedableHasher,
+{
+    eprintln!("Running {} chains of depth {} on {} bytes", name, depth, bytes);
+    let buffer = vec![15; bytes];
+    let mut values = Vec::with_capacity(iters);
+    for _ in 0..iters {
+        let timer = Instant::now();
+        for i in 0..count {
+            let mut seed = i as u64;
+            for _ in 0..depth {
+                let mut hasher = H::with_seed(seed);
+                hasher.write(black_box(&buffer));
+                seed = hasher.finish();
+            }
+            black_box(seed);
+        }
+        values.push(1e9 * timer.elapsed().as_secs_f64() / count as f64);
+    }
+    let (mean, sd) = summarize(&values, robust);
+    eprintln!("    -> {:.2}±{:.2} ns per chain", mean, sd);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}", name, bytes, depth, count, iters, mean, sd)?;
+    Ok(())
+}
+
+/// Tries to find 100 pairs of colliding inputs, as an attacker would do to degrade a hash table.
+/// The `Hasher` interface does not expose internal state, so instead of a meet-in-the-middle attack,
+/// this is a black-box birthday search over 2^22 structured low-entropy keys (`key:` + HEX counter).
+/// For a 64-bit hasher with good quality, the search is expected to fail.
+fn test_hash_dos_resistance<H>(
+    name: &str,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const ATTEMPTS: usize = 2_usize.pow(22);
+    const TARGET_PAIRS: usize = 100;
+    eprintln!("Testing {} for collision search, {} attempts", name, ATTEMPTS);
+    let timer = Instant::now();
+    let mut buffer = *b"key:00000000";
+    let mut hashes: Vec<u64> = (0..ATTEMPTS as u64).map(|i| {
+        fill_hex(buffer[4..].iter_mut().rev(), i);
+        calc::<H>(&buffer)
+    }).collect();
+    hashes.sort_unstable();
+    // All keys are distinct, so each repeated hash value gives one more colliding pair.
+    let pairs = hashes.windows(2).filter(|w| w[0] == w[1]).count().min(TARGET_PAIRS);
+    let success = pairs == TARGET_PAIRS;
+    let seconds = timer.elapsed().as_secs_f64();
+    writeln!(writer, "{}\t{}\t{}\t{}\t{:.3}", name, ATTEMPTS, pairs, success, seconds)?;
+    eprintln!("    -> {:.2} s, {} colliding pairs found", seconds, pairs);
+    Ok(())
+}
+
+/// Measures bandwidth when the buffer is written in chunks of 1, 2, 4, ... bytes up to `target_bytes / 2`,
+/// followed by the remaining bytes, as in a typical streaming pattern.
+/// Bandwidth of single-shot hashing is measured as well, so that the ratio shows the cost of incremental updates.
+fn evaluate_incremental_size<H>(
+    name: &str,
+    target_bytes: usize,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Running {} on {} bytes, written incrementally", name, target_bytes);
+    let buffer = vec![15; target_bytes];
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut size = 1;
+    while size <= target_bytes / 2 {
+        chunks.push(start..start + size);
+        start += size;
+        size *= 2;
+    }
+    if start < target_bytes {
+        chunks.push(start..target_bytes);
+    }
+
+    let mut values = Vec::with_capacity(iters);
+    for _ in 0..iters {
+        let timer = Instant::now();
+        for _ in 0..count {
+            let buffer = black_box(&buffer);
+            let mut hasher = H::default();
+            for chunk in &chunks {
+                hasher.write(&buffer[chunk.clone()]);
+            }
+            black_box(hasher.finish());
+        }
+        let runtime = timer.elapsed();
+        values.push(1e-6 * (count * target_bytes) as f64 / runtime.as_secs_f64());
+    }
+    let (mean, sd) = summarize(&values, robust);
+    let (single_mean, _) = summarize(&measure_bandwidth::<H>(&buffer, count, iters), robust);
+    let ratio = mean / single_mean;
+    eprintln!("    -> {:5.0}±{:5.0} Mb/s, single-shot {:5.0} Mb/s, ratio {:.3}", mean, sd, single_mean, ratio);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.10}\t{:.6}", name, target_bytes, chunks.len(), count,
+        iters, mean, sd, single_mean, ratio)?;
+    Ok(())
+}
+
+/// Check that no proper prefix of a string has the same hash as the full string:
+/// for `count` random alphanumeric strings `s`, compares `hash(s[..k])` with `hash(s)` for all `k` in `1..len(s)`.
+/// Any collision here indicates a serious quality issue.
+fn test_prefix_collision<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const LENGTH: usize = 32;
+    eprintln!("Testing {} for prefix collisions, length {}", name, LENGTH);
+    let timer = Instant::now();
+    let mut buffer = [0; LENGTH];
+    let mut collisions = 0;
+    let mut total = 0;
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = rng.sample(Alphanumeric));
+        let hash0 = calc::<H>(&buffer);
+        for k in 1..LENGTH {
+            collisions += u64::from(calc::<H>(&buffer[..k]) == hash0);
+            total += 1;
+        }
+    }
+    writeln!(writer, "{}\t{}\t{}", name, collisions, total)?;
+    eprintln!("    -> {:.2} s, {} collisions / {}", timer.elapsed().as_secs_f64(), collisions, total);
+    Ok(())
+}
+
+/// Kolmogorov-Smirnov test of hash values of `count` random inputs against the uniform distribution.
+/// Unlike chi-squared test, it does not require binning the outputs.
+/// Hashes are shifted to the highest bits, so that `bits`-bit hashers are compared against the same distribution.
+fn test_ks_uniformity<H>(
+    name: &str,
+    bits: u32,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for uniformity (KS test), length {}", name, length);
+    let timer = Instant::now();
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    let outputs: Vec<u64> = (0..count).map(|_| {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        calc::<H>(&buffer) << (64 - bits)
+    }).collect();
+    let ks = stats::ks_statistic_vs_uniform(&outputs);
+    writeln!(writer, "{}\t{}\t{:.10}\t{}", name, length, ks, count)?;
+    eprintln!("    -> {:.2} s, KS statistic {:.6}", timer.elapsed().as_secs_f64(), ks);
+    Ok(())
+}
+
+/// Check that the hasher incorporates input length: compares hashes of random strings
+/// and the same strings, padded with zero bytes to twice their length.
+/// Any equality means that the hasher silently ignores trailing zeros.
+fn test_length_independence<H>(
+    name: &str,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const COUNT: usize = 1000;
+    eprintln!("Testing {} for trailing zeros handling", name);
+    let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0);
+    for length in [1, 4, 8, 16, 32, 64] {
+        let mut buffer = vec![0; 2 * length];
+        let mut equal = 0;
+        for _ in 0..COUNT {
+            rng.fill(&mut buffer[..length]);
+            equal += usize::from(calc::<H>(&buffer[..length]) == calc::<H>(&buffer));
+        }
+        writeln!(writer, "{}\t{}\t{}\t{}", name, length, equal, COUNT)?;
+        eprintln!("    -> length {}: {} equal / {}", length, equal, COUNT);
+    }
+    Ok(())
+}
+
+/// Finds how many timing iterations are needed for stable bandwidth measurements:
+/// starts with 2 iterations and doubles them until coefficient of variation (SD / mean, or MAD / median
+/// if `robust` is true) drops below 1%, or `max_iters` is reached.
+fn evaluate_convergence<H>(
+    name: &str,
+    bytes: usize,
+    max_iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const COUNT: usize = 2_usize.pow(14);
+    const MAX_CV: f64 = 0.01;
+    eprintln!("Running {} on {} bytes until bandwidth is stable", name, bytes);
+    let buffer = vec![15; bytes];
+    let mut iters = 2;
+    let mut cv;
+    loop {
+        let (location, spread) = summarize(&measure_bandwidth::<H>(&buffer, COUNT, iters), robust);
+        cv = spread / location;
+        if cv < MAX_CV || iters >= max_iters {
+            break;
+        }
+        iters = (2 * iters).min(max_iters);
+    }
+    eprintln!("    -> {} iterations, CV {:.4}", iters, cv);
+    writeln!(writer, "{}\t{}\t{}", name, bytes, iters)?;
+    Ok(())
+}
+
+/// Check uniformity of hash values of `count` random inputs after modular reduction to bucket indices,
+/// as in hash tables. For each modulus, computes chi-squared statistic with `modulus - 1` degrees of freedom.
+fn test_distribution_by_mod<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    moduli: &[u64],
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for distribution modulo {:?}, length {}", name, moduli, length);
+    let timer = Instant::now();
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    let mut buckets: Vec<Vec<u64>> = moduli.iter().map(|&modulus| vec![0; modulus as usize]).collect();
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        let hash = calc::<H>(&buffer);
+        for (&modulus, counts) in moduli.iter().zip(buckets.iter_mut()) {
+            counts[(hash % modulus) as usize] += 1;
+        }
+    }
+    let chi_squared: Vec<f64> = buckets.iter().map(|counts| stats::chi_squared_uniform(counts)).collect();
+    for (modulus, chi2) in moduli.iter().zip(&chi_squared) {
+        writeln!(writer, "{}\t{}\t{}\t{:.3}\t{}", name, length, modulus, chi2, count)?;
+    }
+    eprintln!("    -> {:.2} s, chi-squared {:.0?}", timer.elapsed().as_secs_f64(), chi_squared);
+    Ok(())
+}
+
+/// Measures CPU cycles of single hash invocations on `bytes`-byte input using the time stamp counter,
+/// as wall-clock time is not precise enough for short keys. Reports 50%, 95% and 99% percentiles over `count` calls.
+/// Values include constant overhead of fences and TSC reads.
+#[cfg(target_arch = "x86_64")]
+fn evaluate_cycles<H>(
+    name: &str,
+    bytes: usize,
+    count: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    use core::arch::x86_64::{_rdtsc, _mm_lfence};
+
+    eprintln!("Measuring {} cycles on {} bytes", name, bytes);
+    let buffer = vec![15; bytes];
+    let mut cycles = Vec::with_capacity(count);
+    for _ in 0..count {
+        // lfence prevents the hash from being reordered around TSC reads.
+        let start = unsafe {
+            _mm_lfence();
+            _rdtsc()
+        };
+        black_box(calc::<H>(black_box(&buffer)));
+        let end = unsafe {
+            _mm_lfence();
+            _rdtsc()
+        };
+        cycles.push(end.saturating_sub(start) as f64);
+    }
+    let p50 = stats::quantile(&cycles, 0.50);
+    let p95 = stats::quantile(&cycles, 0.95);
+    let p99 = stats::quantile(&cycles, 0.99);
+    eprintln!("    -> {} / {} / {} cycles (p50 / p95 / p99)", p50, p95, p99);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{}", name, bytes, count, p50, p95, p99)?;
+    Ok(())
+}
+
+/// Compares hashing of concatenated fields `hash(a || b)` with combining field hashes as `hash(a) ^ hash(b)`.
+/// Fields are drawn from a pool of `sqrt(count)` random `half_length`-byte strings, so that the same field
+/// appears in many pairs, as in real records. All `count` pairs are distinct;
+/// the number of collisions shows the quality loss of XOR combination (for example, it is symmetric).
+fn test_xor_combine<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    half_length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for XOR combination of two {}-byte fields", name, half_length);
+    let timer = Instant::now();
+    let pool_size = (count as f64).sqrt().ceil() as usize;
+    let mut pool: Vec<Vec<u8>> = Vec::with_capacity(pool_size);
+    while pool.len() < pool_size {
+        let field: Vec<u8> = (0..half_length).map(|_| rng.sample(Alphanumeric)).collect();
+        if !pool.contains(&field) {
+            pool.push(field);
+        }
+    }
+    let field_hashes: Vec<u64> = pool.iter().map(|field| calc::<H>(field)).collect();
+
+    let mut combined_set: std::collections::HashSet<u64, ahash::RandomState> = Default::default();
+    let mut xor_set: std::collections::HashSet<u64, ahash::RandomState> = Default::default();
+    let mut combined_collisions = 0;
+    let mut xor_collisions = 0;
+    let mut buffer = vec![0; 2 * half_length];
+    for (i, j) in (0..pool_size).flat_map(|i| (0..pool_size).map(move |j| (i, j))).take(count) {
+        buffer[..half_length].copy_from_slice(&pool[i]);
+        buffer[half_length..].copy_from_slice(&pool[j]);
+        combined_collisions += u64::from(!combined_set.insert(calc::<H>(&buffer)));
+        xor_collisions += u64::from(!xor_set.insert(field_hashes[i] ^ field_hashes[j]));
+    }
+    writeln!(writer, "{}\t{}\t{}\t{}", name, half_length, combined_collisions, xor_collisions)?;
+    eprintln!("    -> {:.2} s, {} collisions when concatenated, {} when combined with XOR",
+        timer.elapsed().as_secs_f64(), combined_collisions, xor_collisions);
+    Ok(())
+}
+
+/// Measures total time to build `HashMap<Vec<u8>, u64>` with `n_entries` random `key_len`-byte keys from scratch,
+/// including resizing and collision handling. Keys are generated in advance and moved into the map.
+fn test_mass_insert<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    n_entries: usize,
+    key_len: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Inserting {} keys of {} bytes into {} hash map", n_entries, key_len, name);
+    let mut bytes = generate_bytes(rng);
+    let keys: Vec<Vec<u8>> = (0..n_entries).map(|_| (&mut bytes).take(key_len).collect()).collect();
+    let timer = Instant::now();
+    let mut map: std::collections::HashMap<Vec<u8>, u64, std::hash::BuildHasherDefault<H>> = Default::default();
+    for (i, key) in keys.into_iter().enumerate() {
+        map.insert(key, i as u64);
+    }
+    let seconds = timer.elapsed().as_secs_f64();
+    black_box(&map);
+    let inserts_per_sec = n_entries as f64 / seconds;
+    writeln!(writer, "{}\t{}\t{}\t{:.3}\t{:.0}", name, n_entries, key_len, 1e3 * seconds, inserts_per_sec)?;
+    eprintln!("    -> {:.2} ms, {:.0} inserts/s", 1e3 * seconds, inserts_per_sec);
+    Ok(())
+}
+
+/// Builds a hash map with `n_entries` random `key_len`-byte keys, and measures lookups of known keys (hits),
+/// of absent keys (misses), and of their random mix in equal proportions.
+/// Misses are often more expensive, as the whole probe sequence needs to be traversed.
+fn test_lookup_mixed<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    n_entries: usize,
+    key_len: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Looking up {}-byte keys in {} hash map with {} entries", key_len, name, n_entries);
+    let mut map: std::collections::HashMap<Vec<u8>, u64, std::hash::BuildHasherDefault<H>> = Default::default();
+    let mut hits = Vec::with_capacity(n_entries);
+    let mut misses = Vec::with_capacity(n_entries);
+    {
+        let mut bytes = generate_bytes(rng);
+        let mut new_key = || -> Vec<u8> { (&mut bytes).take(key_len).collect() };
+        for i in 0..n_entries {
+            let key = new_key();
+            hits.push(key.clone());
+            map.insert(key, i as u64);
+        }
+        while misses.len() < n_entries {
+            let key = new_key();
+            if !map.contains_key(&key) {
+                misses.push(key);
+            }
+        }
+    }
+    let mut mixed: Vec<&[u8]> = hits[..n_entries / 2].iter().chain(&misses[..n_entries / 2])
+        .map(Vec::as_slice).collect();
+    rand::seq::SliceRandom::shuffle(&mut mixed[..], rng);
+
+    let measure = |queries: &mut dyn Iterator<Item = &[u8]>| {
+        let timer = Instant::now();
+        let found = queries.filter(|key| map.contains_key(*key)).count();
+        black_box(found);
+        timer.elapsed().as_secs_f64()
+    };
+    let hits_per_sec = n_entries as f64 / measure(&mut hits.iter().map(Vec::as_slice));
+    let misses_per_sec = n_entries as f64 / measure(&mut misses.iter().map(Vec::as_slice));
+    let total_ns_per_op = 1e9 * measure(&mut mixed.iter().copied()) / mixed.len() as f64;
+    writeln!(writer, "{}\t{}\t{}\t{:.0}\t{:.0}\t{:.3}", name, n_entries, key_len, hits_per_sec, misses_per_sec,
+        total_ns_per_op)?;
+    eprintln!("    -> {:.0} hits/s, {:.0} misses/s, {:.2} ns per mixed lookup", hits_per_sec, misses_per_sec,
+        total_ns_per_op);
+    Ok(())
+}
+
+/// Measures bandwidth when the hasher state is cloned (and the clone is discarded) after the first half of the input,
+/// as in incremental digests, and compares it with bandwidth of writing both halves without cloning.
+/// Bandwidth is averaged with mean, or with median if `robust` is true.
+fn evaluate_clone_cost<H>(
+    name: &str,
+    bytes: usize,
+    count: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default + Clone,
+{
+    const ITERS: usize = 256;
+    eprintln!("Running {} on {} bytes, cloned in the middle", name, bytes);
+    let buffer = vec![15; bytes];
+    let (first, second) = buffer.split_at(bytes / 2);
+    let measure = |clone: bool| {
+        let mut values = Vec::with_capacity(ITERS);
+        for _ in 0..ITERS {
+            let timer = Instant::now();
+            for _ in 0..count {
+                let mut hasher = H::default();
+                hasher.write(black_box(first));
+                if clone {
+                    black_box(hasher.clone());
+                }
+                hasher.write(black_box(second));
+                black_box(hasher.finish());
+            }
+            values.push(1e-6 * (count * bytes) as f64 / timer.elapsed().as_secs_f64());
+        }
+        summarize(&values, robust).0
+    };
+    let plain = measure(false);
+    let cloned = measure(true);
+    let ratio = cloned / plain;
+    eprintln!("    -> {:5.0} Mb/s without clone, {:5.0} Mb/s with clone, ratio {:.3}", plain, cloned, ratio);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.6}", name, bytes, count, ITERS, plain, cloned, ratio)?;
+    Ok(())
+}
+
+/// Strict avalanche criterion: flips each input bit of `count` random `length`-byte inputs, and for every pair
+/// (input bit, output bit) computes the probability that the output bit changes.
+/// Returns mean and maximal absolute deviation of these probabilities from 0.5, over the lower `bits` output bits.
+fn strict_avalanche_deviation<H>(
+    bits: u32,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+) -> (f64, f64)
+where H: Hasher + Default,
+{
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    let mut flips = vec![[0_u64; 64]; 8 * length];
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        let hash0 = calc::<H>(&buffer);
+        for (i, out_flips) in flips.iter_mut().enumerate() {
+            buffer[i / 8] ^= 1 << (i % 8);
+            let mut diff = calc::<H>(&buffer) ^ hash0;
+            buffer[i / 8] ^= 1 << (i % 8);
+            while diff != 0 {
+                out_flips[diff.trailing_zeros() as usize] += 1;
+                diff &= diff - 1;
+            }
+        }
+    }
+    let deviations: Vec<f64> = flips.iter()
+        .flat_map(|out_flips| out_flips[..bits as usize].iter())
+        .map(|&c| (c as f64 / count as f64 - 0.5).abs())
+        .collect();
+    let mean = deviations.iter().sum::<f64>() / deviations.len() as f64;
+    (mean, deviations.iter().copied().fold(0.0, f64::max))
+}
+
+/// Measures how strict avalanche criterion deviation changes with input length.
+/// Growing deviation for longer inputs suggests insufficient mixing of early bytes.
+/// For an ideal hasher, mean deviation is about `0.4 / sqrt(count)`.
+fn test_incremental_avalanche<H>(
+    name: &str,
+    bits: u32,
+    rng: &mut impl Rng,
+    count: usize,
+    sizes: &[usize],
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    for &length in sizes {
+        eprintln!("Testing {} for strict avalanche criterion, length {}", name, length);
+        let timer = Instant::now();
+        let (mean, max) = strict_avalanche_deviation::<H>(bits, rng, count, length);
+        writeln!(writer, "{}\t{}\t{:.6}\t{:.6}\t{}", name, length, mean, max, count)?;
+        eprintln!("    -> {:.2} s, mean deviation {:.5}, max deviation {:.5}", timer.elapsed().as_secs_f64(),
+            mean, max);
+    }
+    Ok(())
+}
+
+/// Measures bandwidth on input sizes around one and two internal blocks of `block_size` bytes.
+/// Difference between exact multiples of the block size and one more byte shows the cost of tail handling.
+fn evaluate_around_block_boundaries<H>(
+    name: &str,
+    block_size: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const COUNT: usize = 2_usize.pow(16);
+    const ITERS: usize = 256;
+    for bytes in [block_size - 1, block_size, block_size + 1, 2 * block_size - 1, 2 * block_size, 2 * block_size + 1] {
+        let is_boundary = bytes % block_size == 0;
+        eprintln!("Running {} on {} bytes (block size {})", name, bytes, block_size);
+        let (mean, sd) = summarize(&measure_bandwidth::<H>(&vec![15; bytes], COUNT, ITERS), robust);
+        eprintln!("    -> {:5.0}±{:5.0} Mb/s", mean, sd);
+        writeln!(writer, "{}\t{}\t{}\t{}\t{:.10}\t{:.10}", name, block_size, bytes, is_boundary, mean, sd)?;
+    }
+    Ok(())
+}
+
+/// Check that hash values of `count` random inputs cover the full output range: records minimal and maximal
+/// output, fraction of outputs with the top bit set, and fraction of outputs for each value of the top 8 bits
+/// (expected 1/256 each). Top bits are taken from the `bits` meaningful bits of the output.
+/// Writes one row per 8-bit prefix, summary values are repeated.
+fn test_output_range<H>(
+    name: &str,
+    bits: u32,
+    rng: &mut impl Rng,
+    count: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const LENGTH: usize = 16;
+    eprintln!("Testing {} for output range, length {}", name, LENGTH);
+    let timer = Instant::now();
+    let mut buffer = [0; LENGTH];
+    let mut bytes = generate_bytes(rng);
+    let mut min = u64::MAX;
+    let mut max = 0;
+    let mut prefix_counts = [0_u64; 256];
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        let hash = calc::<H>(&buffer);
+        min = min.min(hash);
+        max = max.max(hash);
+        prefix_counts[(hash >> (bits - 8)) as usize & 0xff] += 1;
+    }
+    let top_bit_fraction = prefix_counts[128..].iter().sum::<u64>() as f64 / count as f64;
+    for (prefix, &prefix_count) in prefix_counts.iter().enumerate() {
+        writeln!(writer, "{}\t{}\t{}\t{:.6}\t{}\t{:.8}", name, min, max, top_bit_fraction, prefix,
+            prefix_count as f64 / count as f64)?;
+    }
+    let (min_count, max_count) = (prefix_counts.iter().min().unwrap(), prefix_counts.iter().max().unwrap());
+    eprintln!("    -> {:.2} s, min {:#018x}, max {:#018x}, top bit {:.4}, prefix fractions {:.5}-{:.5}",
+        timer.elapsed().as_secs_f64(), min, max, top_bit_fraction, *min_count as f64 / count as f64,
+        *max_count as f64 / count as f64);
+    Ok(())
+}
+
+/// Measures bandwidth when `input_bytes`-byte inputs are taken at random offsets of a large random pool,
+/// so that most inputs are not in cache, and compares it with hashing the same buffer.
+/// Ratio close to 1 means that the hasher is compute-bound rather than memory-bound.
+fn evaluate_cache_miss<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    pool: &[u8],
+    input_bytes: usize,
+    count: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const ITERS: usize = 16;
+    let pool_size = pool.len();
+    let pool_size_mib = pool_size / (1024 * 1024);
+    eprintln!("Running {} on {} bytes at random offsets of {} MiB pool", name, input_bytes, pool_size_mib);
+    assert!(pool_size > input_bytes);
+    let offsets: Vec<usize> = (0..count).map(|_| rng.gen_range(0..pool_size - input_bytes)).collect();
+
+    let mut values = Vec::with_capacity(ITERS);
+    for _ in 0..ITERS {
+        let timer = Instant::now();
+        for &offset in &offsets {
+            black_box(calc::<H>(black_box(&pool[offset..offset + input_bytes])));
+        }
+        values.push(1e-6 * (count * input_bytes) as f64 / timer.elapsed().as_secs_f64());
+    }
+    let pool_bandwidth = summarize(&values, robust).0;
+    let cached_bandwidth = summarize(&measure_bandwidth::<H>(&pool[..input_bytes], count, ITERS), robust).0;
+    let ratio = pool_bandwidth / cached_bandwidth;
+    eprintln!("    -> {:5.0} Mb/s from pool, {:5.0} Mb/s cached, ratio {:.3}", pool_bandwidth, cached_bandwidth, ratio);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.6}", name, pool_size_mib, input_bytes, count, ITERS,
+        pool_bandwidth, cached_bandwidth, ratio)?;
+    Ok(())
+}
+
+/// Counts collisions on `count` random alphanumeric inputs (without duplicates),
+/// hashed in random order and in sorted order.
+/// Hashers are stateless, so the number of collisions should not depend on the order;
+/// any difference would indicate that the hash of an input depends on previously hashed inputs.
+fn test_sorted_inputs<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for collisions on sorted and shuffled inputs, length {}", name, length);
+    let timer = Instant::now();
+    let mut inputs: Vec<Vec<u8>> = (0..count).map(|_| (0..length).map(|_| rng.sample(Alphanumeric)).collect())
+        .collect();
+    // Duplicate inputs would be counted as collisions.
+    inputs.sort_unstable();
+    inputs.dedup();
+    rand::seq::SliceRandom::shuffle(&mut inputs[..], rng);
+    let shuffled_collisions = count_collisions::<H>(&inputs);
+    inputs.sort_unstable();
+    let sorted_collisions = count_collisions::<H>(&inputs);
+    writeln!(writer, "{}\t{}\t{}\t{}", name, length, sorted_collisions, shuffled_collisions)?;
+    eprintln!("    -> {:.2} s, {} collisions sorted, {} shuffled / {}", timer.elapsed().as_secs_f64(),
+        sorted_collisions, shuffled_collisions, inputs.len());
+    Ok(())
+}
+
+/// Run-length encodes hash values (as little-endian bytes) of `count` random inputs, and reports the ratio
+/// between encoded and raw sizes. Each run takes two bytes (value and length up to 255), so for uniform bytes
+/// the expected ratio is `2 * 255 / 256 = 1.992`; lower values indicate locally correlated output.
+fn test_compressibility<H>(
+    name: &str,
+    bits: u32,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for compressibility of hash values, length {}", name, length);
+    let timer = Instant::now();
+    let hash_bytes = bits as usize / 8;
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    let mut stream = Vec::with_capacity(count * hash_bytes);
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        stream.extend_from_slice(&calc::<H>(&buffer).to_le_bytes()[..hash_bytes]);
+    }
+    let mut runs = 0;
+    let mut i = 0;
+    while i < stream.len() {
+        let run = stream[i..].iter().take(255).take_while(|&&b| b == stream[i]).count();
+        runs += 1;
+        i += run;
+    }
+    let ratio = 2.0 * runs as f64 / stream.len() as f64;
+    writeln!(writer, "{}\t{}\t{}\t{:.6}", name, length, count, ratio)?;
+    eprintln!("    -> {:.2} s, RLE ratio {:.5}", timer.elapsed().as_secs_f64(), ratio);
+    Ok(())
+}
+
+/// Same as `test_randomness`, but reports the average number of changed output bits separately
+/// for each changed byte position. First and last bytes may show weaker avalanche in some hashers.
+fn test_randomness_by_position<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for randomness by byte position, length {}", name, length);
+    let timer = Instant::now();
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    let mut changed_bits = vec![0_u64; length];
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        let hash0 = calc::<H>(&buffer);
+        for (i, changed) in changed_bits.iter_mut().enumerate() {
+            let b = buffer[i];
+            buffer[i] = b.wrapping_add(1);
+            *changed += u64::from((calc::<H>(&buffer) ^ hash0).count_ones());
+            buffer[i] = b;
+        }
+    }
+    let averages: Vec<f64> = changed_bits.iter().map(|&c| c as f64 / count as f64).collect();
+    for (position, avg) in averages.iter().enumerate() {
+        writeln!(writer, "{}\t{}\t{}\t{:.6}", name, length, position, avg)?;
+    }
+    let (min, max) = averages.iter().fold((f64::INFINITY, 0.0_f64), |(min, max), &x| (min.min(x), max.max(x)));
+    eprintln!("    -> {:.2} s, {:.3}-{:.3} bits changed on average", timer.elapsed().as_secs_f64(), min, max);
+    Ok(())
+}
+
+/// Measures bandwidth on `bytes`-byte inputs, starting at offsets 0..64 of the same buffer.
+/// Hashers that use unaligned loads should show the same bandwidth for all offsets,
+/// while hashers with aligned-only SIMD paths would show periodic drops.
+fn evaluate_alignment_sweep<H>(
+    name: &str,
+    bytes: usize,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const MAX_OFFSET: usize = 64;
+    eprintln!("Running {} on {} bytes at offsets 0..{}", name, bytes, MAX_OFFSET);
+    let buffer = vec![15; bytes + MAX_OFFSET];
+    let mut means = Vec::with_capacity(MAX_OFFSET);
+    for offset in 0..MAX_OFFSET {
+        let (mean, sd) = summarize(&measure_bandwidth::<H>(&buffer[offset..offset + bytes], count, iters), robust);
+        writeln!(writer, "{}\t{}\t{}\t{}\t{:.10}\t{:.10}", name, bytes, offset,
+            (buffer.as_ptr() as usize + offset) % MAX_OFFSET, mean, sd)?;
+        means.push(mean);
+    }
+    let (min, max) = means.iter().fold((f64::INFINITY, 0.0_f64), |(min, max), &x| (min.min(x), max.max(x)));
+    eprintln!("    -> {:5.0}-{:5.0} Mb/s", min, max);
+    Ok(())
+}
+
+/// Reads all regular files in `dir` (not recursively) and measures bandwidth of hashing each file as a whole.
+fn evaluate_files<H>(
+    name: &str,
+    dir: &Path,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    // Each timing iteration hashes at least 16 MiB in total.
+    const MIN_TOTAL_BYTES: usize = 16 * 1024 * 1024;
+    eprintln!("Running {} on files from {}", name, dir.display());
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            paths.push(entry.path());
+        }
+    }
+    paths.sort();
+    let files = paths.into_iter().map(|path| fs::read(&path).map(|contents| (path, contents)))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let timer = Instant::now();
+    let mut total_bytes = 0;
+    for (path, contents) in &files {
+        let count = (MIN_TOTAL_BYTES / contents.len().max(1)).max(1);
+        let (mean, sd) = summarize(&measure_bandwidth::<H>(contents, count, iters), robust);
+        writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}", name, path.display(), contents.len(), count, iters,
+            mean, sd)?;
+        total_bytes += contents.len();
+    }
+    eprintln!("    -> {:.2} s, {} files, {} bytes in total", timer.elapsed().as_secs_f64(), files.len(), total_bytes);
+    Ok(())
+}
+
+/// Counts collisions on `N`-byte strings that differ only in two bytes at the beginning, in the middle
+/// and at the end. Large difference between the positions indicates position-dependent quality.
+fn test_variant_position<H, const N: usize>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for collisions on {}-byte strings with variable prefix, middle and suffix", name, N);
+    let timer = Instant::now();
+    let middle = N / 2 - 1;
+    let mut summary = Vec::with_capacity(3);
+    for (variant, var_start, inputs) in [
+        ("prefix", 0, gen::prefix_variant_strings::<N>(rng, count)),
+        ("middle", middle, gen::middle_variant_strings::<N>(rng, count, middle)),
+        ("suffix", N - 2, gen::suffix_variant_strings::<N>(rng, count)),
+    ] {
+        let collisions = count_collisions::<H>(&inputs);
+        writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{}", name, N, variant, var_start, collisions, inputs.len())?;
+        summary.push(format!("{} {}", collisions, variant));
+    }
+    eprintln!("    -> {:.2} s, collisions: {} / {}", timer.elapsed().as_secs_f64(), summary.join(", "), count);
+    Ok(())
+}
+
+/// Records hashes of the empty input and of a single zero byte. Hashers that return the same value for both
+/// do not distinguish an empty input from a zero byte, and hashers that return 0 for both are degenerate.
+fn test_empty_input<H>(
+    name: &str,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} on empty input", name);
+    let empty_hash = calc::<H>(&[]);
+    let zero_hash = calc::<H>(&[0]);
+    let equal = empty_hash == zero_hash;
+    let degenerate = equal && empty_hash == 0;
+    writeln!(writer, "{}\t{:016x}\t{:016x}\t{}\t{}", name, empty_hash, zero_hash, equal, degenerate)?;
+    eprintln!("    -> hash(\"\") = {:016x}, hash(\"\\0\") = {:016x}{}", empty_hash, zero_hash,
+        if degenerate { ", DEGENERATE" } else if equal { ", EQUAL" } else { "" });
+    Ok(())
+}
+
+/// Compares hashes of `count` random strings and the same strings, followed by 1, 2, 4 and 8 zero bytes.
+/// Any equality means that the hasher does not distinguish inputs that differ only by trailing zeros.
+fn test_zero_suffix_collision<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const SUFFIX_LENS: [usize; 4] = [1, 2, 4, 8];
+    eprintln!("Testing {} for collisions with zero suffixes", name);
+    let mut total_equal = 0;
+    for base_length in [1, 3, 4, 7, 8, 15, 16, 32] {
+        let mut buffer = vec![0; base_length + SUFFIX_LENS[SUFFIX_LENS.len() - 1]];
+        let mut equal = [0; SUFFIX_LENS.len()];
+        for _ in 0..count {
+            rng.fill(&mut buffer[..base_length]);
+            let hash0 = calc::<H>(&buffer[..base_length]);
+            for (&suffix_len, eq) in SUFFIX_LENS.iter().zip(equal.iter_mut()) {
+                *eq += usize::from(calc::<H>(&buffer[..base_length + suffix_len]) == hash0);
+            }
+        }
+        for (&suffix_len, &eq) in SUFFIX_LENS.iter().zip(equal.iter()) {
+            writeln!(writer, "{}\t{}\t{}\t{}", name, base_length, suffix_len, eq)?;
+        }
+        total_equal += equal.iter().sum::<usize>();
+    }
+    if total_equal > 0 {
+        eprintln!("    -> WARNING: {} inputs hash the same as with a zero suffix", total_equal);
+    } else {
+        eprintln!("    -> no equal hashes");
+    }
+    Ok(())
+}
+
+/// Measures bandwidth when `total_bytes` are written with consecutive `write` calls of `write_size` bytes each,
+/// and compares it with a single `write` call of all `total_bytes`.
+/// Ratio below 1 shows the overhead of additional `write` calls.
+fn evaluate_write_call_overhead<H>(
+    name: &str,
+    total_bytes: usize,
+    write_size: usize,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Running {} on {} bytes, written in {}-byte calls", name, total_bytes, write_size);
+    let buffer = vec![15; total_bytes];
+    let measure = |chunk_size: usize| {
+        let mut values = Vec::with_capacity(iters);
+        for _ in 0..iters {
+            let timer = Instant::now();
+            for _ in 0..count {
+                let mut hasher = H::default();
+                black_box(&buffer).chunks(chunk_size).for_each(|chunk| hasher.write(chunk));
+                black_box(hasher.finish());
+            }
+            values.push(1e-6 * (count * total_bytes) as f64 / timer.elapsed().as_secs_f64());
+        }
+        summarize(&values, robust)
+    };
+    let (mean, sd) = measure(write_size);
+    let (baseline, _) = measure(total_bytes);
+    let ratio = mean / baseline;
+    eprintln!("    -> {:5.0}±{:5.0} Mb/s, single write {:5.0} Mb/s, ratio {:.3}", mean, sd, baseline, ratio);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.10}\t{:.6}", name, total_bytes, write_size, count, iters,
+        mean, sd, baseline, ratio)?;
+    Ok(())
+}
+
+/// Measures aggregate bandwidth of `pipeline_depth` independent hashers, running in the same thread.
+/// Each hasher processes its own `bytes`-byte part of the buffer, and writes are interleaved in 64-byte blocks,
+/// so that the CPU can overlap independent computations. Bandwidth stops growing with depth
+/// once the execution units are saturated.
+fn evaluate_pipeline<H>(
+    name: &str,
+    bytes: usize,
+    pipeline_depth: usize,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const BLOCK: usize = 64;
+    eprintln!("Running {} on {} bytes, {} interleaved hashers", name, bytes, pipeline_depth);
+    let buffer = vec![15; bytes * pipeline_depth];
+    let parts: Vec<&[u8]> = buffer.chunks(bytes).collect();
+    let mut hashers: Vec<H> = (0..pipeline_depth).map(|_| H::default()).collect();
+    let mut values = Vec::with_capacity(iters);
+    for _ in 0..iters {
+        let timer = Instant::now();
+        for _ in 0..count {
+            hashers.iter_mut().for_each(|hasher| *hasher = H::default());
+            for start in (0..bytes).step_by(BLOCK) {
+                let end = bytes.min(start + BLOCK);
+                for (hasher, part) in hashers.iter_mut().zip(&parts) {
+                    hasher.write(black_box(&part[start..end]));
+                }
+            }
+            hashers.iter().for_each(|hasher| { black_box(hasher.finish()); });
+        }
+        values.push(1e-6 * (count * buffer.len()) as f64 / timer.elapsed().as_secs_f64());
+    }
+    let (mean, sd) = summarize(&values, robust);
+    eprintln!("    -> {:5.0}±{:5.0} Mb/s", mean, sd);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}", name, bytes, pipeline_depth, count, iters,
+        mean, sd)?;
+    Ok(())
+}
+
+/// Measures bandwidth on `bytes`-byte input, where the first `entropy_level * bytes` bytes are random
+/// and the remaining bytes are constant. Hashers with data-dependent branches may be faster on low-entropy inputs.
+fn evaluate_vs_entropy<H>(
+    name: &str,
+    bytes: usize,
+    entropy_level: f64,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    assert!((0.0..=1.0).contains(&entropy_level));
+    eprintln!("Running {} on {} bytes, entropy level {}", name, bytes, entropy_level);
+    let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0);
+    let mut buffer = vec![15; bytes];
+    let random_bytes = (entropy_level * bytes as f64).round() as usize;
+    rng.fill(&mut buffer[..random_bytes]);
+    let (mean, sd) = summarize(&measure_bandwidth::<H>(&buffer, count, iters), robust);
+    eprintln!("    -> {:5.0}±{:5.0} Mb/s", mean, sd);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}", name, bytes, entropy_level, random_bytes, count, iters,
+        mean, sd)?;
+    Ok(())
+}
+
+/// Counts even and odd parity (popcount) of hash values of `count` random inputs.
+/// Both should be equally likely; chi-squared statistic above 3.84 is significant at the 5% level (1 d.f.).
+fn test_parity_uniformity<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for parity uniformity, length {}", name, length);
+    let timer = Instant::now();
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    let mut parity_counts = [0_u64; 2];
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        parity_counts[calc::<H>(&buffer).count_ones() as usize % 2] += 1;
+    }
+    let chi2 = stats::chi_squared_uniform(&parity_counts);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{:.6}", name, length, parity_counts[0], parity_counts[1], chi2)?;
+    eprintln!("    -> {:.2} s, {} even / {} odd, chi2 {:.3}", timer.elapsed().as_secs_f64(), parity_counts[0],
+        parity_counts[1], chi2);
+    Ok(())
+}
+
+/// Compares bandwidth of hashing `total_bytes` with one call and as separate hashes of `chunk_size`-byte chunks.
+/// Efficiency ratio (chunked / bulk bandwidth) close to 1 means that the per-call overhead is negligible.
+fn evaluate_bulk_vs_chunks<H>(
+    name: &str,
+    total_bytes: usize,
+    chunk_sizes: &[usize],
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Running {} on {} bytes, as a whole and in chunks of {:?} bytes", name, total_bytes, chunk_sizes);
+    let buffer = vec![15; total_bytes];
+    let bulk = summarize(&measure_bandwidth::<H>(&buffer, count, iters), robust).0;
+    let mut ratios = Vec::with_capacity(chunk_sizes.len());
+    for &chunk_size in chunk_sizes {
+        let mut values = Vec::with_capacity(iters);
+        for _ in 0..iters {
+            let timer = Instant::now();
+            for _ in 0..count {
+                for chunk in black_box(&buffer).chunks(chunk_size) {
+                    black_box(calc::<H>(chunk));
+                }
+            }
+            values.push(1e-6 * (count * total_bytes) as f64 / timer.elapsed().as_secs_f64());
+        }
+        let chunked = summarize(&values, robust).0;
+        let ratio = chunked / bulk;
+        writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.6}", name, total_bytes, chunk_size, count, iters,
+            bulk, chunked, ratio)?;
+        ratios.push(format!("{:.3}", ratio));
+    }
+    eprintln!("    -> bulk {:5.0} Mb/s, efficiency ratios {}", bulk, ratios.join(", "));
+    Ok(())
+}
+
+/// Check collisions on sibling keys (such as `user:000001`, `user:000002`, ...): `total_len`-byte strings
+/// with a common random alphanumeric prefix, where only the last `differ_at` bytes differ.
+/// The number of keys is limited by the number of distinct values of the last `differ_at` bytes.
+fn test_sibling_collisions<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    total_len: usize,
+    differ_at: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    assert!(differ_at > 0 && differ_at <= total_len.min(8));
+    let count = count.min(1_usize.checked_shl(8 * differ_at as u32).unwrap_or(usize::MAX));
+    eprintln!("Testing {} for collisions on {}-byte sibling keys, last {} bytes differ", name, total_len, differ_at);
+    let timer = Instant::now();
+    let mut buffer: Vec<u8> = (0..total_len).map(|_| rng.sample(Alphanumeric)).collect();
+    let start = total_len - differ_at;
+    let mut collisions = 0;
+    let mut set: std::collections::HashSet<u64, ahash::RandomState> = Default::default();
+    for val in 0..count as u64 {
+        buffer[start..].copy_from_slice(&val.to_be_bytes()[8 - differ_at..]);
+        collisions += u64::from(!set.insert(calc::<H>(&buffer)));
+    }
+    let rate = collisions as f64 / count as f64;
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.6e}", name, total_len, differ_at, collisions, count, rate)?;
+    eprintln!("    -> {:.2} s, {} collisions / {}", timer.elapsed().as_secs_f64(), collisions, count);
+    Ok(())
+}
+
+/// Check whether hashes of consecutive prefixes of the same string are correlated: for `count` random strings,
+/// hashes prefixes of lengths 1..=`max_length`, and computes Pearson correlation between hashes of prefixes
+/// of lengths `n` and `n + lag`, pooled over all strings and `n`. Expected correlation is 0 for all lags.
+fn test_sequential_length_correlation<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    max_length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const MAX_LAG: usize = 8;
+    eprintln!("Testing {} for correlation between hashes of consecutive prefixes", name);
+    let timer = Instant::now();
+    let max_lag = MAX_LAG.min(max_length - 1);
+    let mut buffer = vec![0; max_length];
+    let mut hashes = vec![Vec::with_capacity(count); max_length];
+    for _ in 0..count {
+        rng.fill(&mut buffer[..]);
+        for (length, length_hashes) in hashes.iter_mut().enumerate() {
+            length_hashes.push(calc::<H>(&buffer[..=length]) as f64);
+        }
+    }
+    let mut max_abs_corr = 0.0_f64;
+    for lag in 1..=max_lag {
+        let a: Vec<f64> = hashes[..max_length - lag].iter().flatten().copied().collect();
+        let b: Vec<f64> = hashes[lag..].iter().flatten().copied().collect();
+        let corr = stats::pearson(&a, &b);
+        writeln!(writer, "{}\t{}\t{:.6}", name, lag, corr)?;
+        max_abs_corr = max_abs_corr.max(corr.abs());
+    }
+    eprintln!("    -> {:.2} s, max |autocorrelation| {:.5}", timer.elapsed().as_secs_f64(), max_abs_corr);
+    Ok(())
+}
+
+/// Compares bandwidth of single hash calls, each preceded by a `eviction_size_kb` KiB memset (cold),
+/// with the same calls without eviction (warm). Each call is timed separately in both cases.
+/// A memset only evicts data directly, but a large enough memset also evicts code from the shared L2 cache,
+/// so hashers with large code footprint suffer more on cold starts.
+fn evaluate_icache<H>(
+    name: &str,
+    bytes: usize,
+    eviction_size_kb: usize,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Running {} on {} bytes, with and without {} KiB eviction", name, bytes, eviction_size_kb);
+    let buffer = vec![15; bytes];
+    let mut eviction = vec![0_u8; eviction_size_kb * 1024];
+    let mut measure = |evict: bool| {
+        let mut values = Vec::with_capacity(iters);
+        for iter in 0..iters {
+            let mut runtime = 0.0;
+            for _ in 0..count {
+                if evict {
+                    black_box(&mut eviction[..]).fill(iter as u8);
+                }
+                let timer = Instant::now();
+                black_box(calc::<H>(black_box(&buffer)));
+                runtime += timer.elapsed().as_secs_f64();
+            }
+            values.push(1e-6 * (count * bytes) as f64 / runtime);
+        }
+        summarize(&values, robust).0
+    };
+    let cold = measure(true);
+    let warm = measure(false);
+    eprintln!("    -> cold {:5.0} Mb/s, warm {:5.0} Mb/s, ratio {:.3}", cold, warm, cold / warm);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.6}", name, bytes, eviction_size_kb, count, iters,
+        cold, warm, cold / warm)?;
+    Ok(())
+}
+
+/// Same as `test_randomness`, but on structured inputs: sequential integers 0, 1, ..., `count - 1`,
+/// encoded as little-endian u64 and padded with zeros to `length` bytes.
+fn test_randomness_structured<H>(
+    name: &str,
+    bits: u32,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    assert!(length >= 8);
+    eprintln!("Testing {} for randomness on sequential inputs, length {}", name, length);
+    let timer = Instant::now();
+    let mut buffer = vec![0; length];
+    let mut matches_count = [0_u64; 65];
+    for i in 0..count as u64 {
+        buffer[..8].copy_from_slice(&i.to_le_bytes());
+        count_changed_bits::<H>(&mut buffer, &mut matches_count);
+    }
+    let average_change = matches_count.into_iter().enumerate()
+        .map(|(i, c)| (i as u64 * c) as f64)
+        .sum::<f64>()
+        / (length * count) as f64;
+    let randomness01 = 1.0 - (2.0 * average_change / f64::from(bits) - 1.0).abs();
+    writeln!(writer, "{}\t{}\t{}\t{:.7}\t{:.10}", name, bits, length, average_change, randomness01)?;
+    eprintln!("    -> {:.2} s, {:.3} bits changed on average, randomness {:.5}", timer.elapsed().as_secs_f64(),
+        average_change, randomness01);
+    Ok(())
+}
+
+/// Measures bandwidth of hashing `n_fields` fields of `bytes_per_field` bytes separately and combining them
+/// as `hash(a) ^ hash(b).rotate_left(32)`, and compares it with hashing a single field.
+/// Per-field overhead is the difference between time per field in the combined and in the single-field case.
+fn evaluate_combine<H>(
+    name: &str,
+    bytes_per_field: usize,
+    n_fields: usize,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Running {} on {} fields of {} bytes, combined with XOR", name, n_fields, bytes_per_field);
+    let buffer = vec![15; bytes_per_field * n_fields];
+    let mut values = Vec::with_capacity(iters);
+    for _ in 0..iters {
+        let timer = Instant::now();
+        for _ in 0..count {
+            let combined = black_box(&buffer).chunks(bytes_per_field)
+                .fold(0_u64, |acc, field| acc.rotate_left(32) ^ calc::<H>(field));
+            black_box(combined);
+        }
+        values.push(1e-6 * (count * buffer.len()) as f64 / timer.elapsed().as_secs_f64());
+    }
+    let combined = summarize(&values, robust).0;
+    let single = summarize(&measure_bandwidth::<H>(&buffer[..bytes_per_field], count, iters), robust).0;
+    // Bandwidth is in bytes per microsecond.
+    let overhead_ns = 1e3 * bytes_per_field as f64 * (1.0 / combined - 1.0 / single);
+    eprintln!("    -> {:5.0} Mb/s combined, {:5.0} Mb/s single field, overhead {:.2} ns per field", combined, single,
+        overhead_ns);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.6}", name, bytes_per_field, n_fields, count, iters,
+        combined, single, overhead_ns)?;
+    Ok(())
+}
+
+/// Counts `00`, `01`, `10` and `11` transitions (higher bit first) between adjacent bits of hash values
+/// of `count` random inputs (`bits - 1` transitions per hash). For uniform output, each has frequency 0.25.
+fn test_bit_transitions<H>(
+    name: &str,
+    bits: u32,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const TRANSITIONS: [&str; 4] = ["00", "01", "10", "11"];
+    eprintln!("Testing {} for adjacent bit transitions, length {}", name, length);
+    let timer = Instant::now();
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    let mut transition_counts = [0_u64; 4];
+    for _ in 0..count {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        let hash = calc::<H>(&buffer);
+        for i in 0..bits - 1 {
+            transition_counts[(hash >> i & 0b11) as usize] += 1;
+        }
+    }
+    let total = transition_counts.iter().sum::<u64>() as f64;
+    let frequencies = transition_counts.map(|c| c as f64 / total);
+    for (transition, (c, freq)) in TRANSITIONS.iter().zip(transition_counts.iter().zip(&frequencies)) {
+        writeln!(writer, "{}\t{}\t{}\t{}\t{:.6}", name, length, transition, c, freq)?;
+    }
+    eprintln!("    -> {:.2} s, frequencies {:.4?}", timer.elapsed().as_secs_f64(), frequencies);
+    Ok(())
+}
+
+/// Approximates brute-force pre-image difficulty: for random target values, counts random 16-byte inputs
+/// that need to be tried until the low 20, 24 and 28 bits of the hash match the target.
+/// For a uniform hash, the mean number of trials is 2^bits. Search is stopped after 16 * 2^bits trials,
+/// in which case all tried inputs are counted.
+fn test_preimage_difficulty<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    // Number of bits and number of targets.
+    const DEPTHS: [(u32, usize); 3] = [(20, 16), (24, 4), (28, 1)];
+    eprintln!("Testing {} for pre-image difficulty", name);
+    for (bits, n_targets) in DEPTHS {
+        let timer = Instant::now();
+        let mask = (1_u64 << bits) - 1;
+        let max_trials = 16_u64 << bits;
+        let mut total_trials = 0;
+        let mut found = 0;
+        for _ in 0..n_targets {
+            let target = rng.gen::<u64>() & mask;
+            let mut trials = 0;
+            while trials < max_trials {
+                trials += 1;
+                if calc::<H>(&rng.gen::<u128>().to_le_bytes()) & mask == target {
+                    found += 1;
+                    break;
+                }
+            }
+            total_trials += trials;
+        }
+        let mean_trials = total_trials as f64 / n_targets as f64;
+        writeln!(writer, "{}\t{}\t{}\t{}\t{:.1}\t{}", name, bits, n_targets, found, mean_trials, 1_u64 << bits)?;
+        eprintln!("    -> {:.2} s, {} bits: {:.0} trials on average (expected {})", timer.elapsed().as_secs_f64(),
+            bits, mean_trials, 1_u64 << bits);
+    }
+    Ok(())
+}
+
+/// Applies the Wald-Wolfowitz runs test to the sequence of the lowest bits of hash values of `count`
+/// random inputs. Small p-values indicate that the lowest bit is not independent between consecutive inputs.
+fn test_runs_randomness<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} with the runs test on the lowest bit, length {}", name, length);
+    let timer = Instant::now();
+    let mut buffer = vec![0; length];
+    let mut bytes = generate_bytes(rng);
+    let lsb: Vec<bool> = (0..count).map(|_| {
+        buffer.iter_mut().for_each(|b| *b = bytes.next().unwrap());
+        calc::<H>(&buffer) & 1 == 1
+    }).collect();
+    let (z, p_value) = stats::runs_test(&lsb);
+    writeln!(writer, "{}\t{}\t{}\t{:.6}\t{:.6e}", name, length, count, z, p_value)?;
+    eprintln!("    -> {:.2} s, Z = {:.3}, p-value {:.4}", timer.elapsed().as_secs_f64(), z, p_value);
+    Ok(())
+}
+
+/// Counts collisions in the low 24 bits of hashes of the same `count` random inputs under `n_seeds` random seeds.
+/// With a properly seeded hasher, collisions differ between seeds, so a set of inputs that collides under one seed
+/// is not useful for an attack on another seed. Zero variance of the collision rate across seeds means
+/// that the colliding inputs do not depend on the seed.
+fn test_seeded_security<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    n_seeds: usize,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: SeedableHasher,
+{
+    const MASK: u64 = (1 << 24) - 1;
+    eprintln!("Testing {} for seed dependence of collisions, length {}", name, length);
+    let timer = Instant::now();
+    let inputs = gen::random_binary(rng, count, length);
+    let mut rates = Vec::with_capacity(n_seeds);
+    let mut hashes = Vec::with_capacity(count);
+    for _ in 0..n_seeds {
+        let seed = rng.gen();
+        hashes.clear();
+        hashes.extend(inputs.iter().map(|input| {
+            let mut hasher = H::with_seed(seed);
+            hasher.write(input);
+            hasher.finish() & MASK
+        }));
+        hashes.sort_unstable();
+        let collisions = hashes.windows(2).filter(|w| w[0] == w[1]).count();
+        rates.push(collisions as f64 / count as f64);
+    }
+    let (mean, var) = mean_variance(&rates);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{:.6e}\t{:.6e}", name, length, n_seeds, count, mean, var)?;
+    eprintln!("    -> {:.2} s, collision rate {:.3e}, variance across seeds {:.3e}", timer.elapsed().as_secs_f64(),
+        mean, var);
+    Ok(())
+}
+
+/// Measures bandwidth when the first input byte of each hash is XOR-ed with the previous hash value,
+/// so that consecutive hashes cannot be overlapped by out-of-order execution,
+/// and compares it with the bandwidth of independent hashes (as in `evaluate`).
+fn evaluate_data_dependent<H>(
+    name: &str,
+    bytes: usize,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Running {} on {} bytes, each input depends on the previous hash", name, bytes);
+    let mut buffer = vec![15; bytes];
+    let mut values = Vec::with_capacity(iters);
+    let mut prev_hash = 0;
+    for _ in 0..iters {
+        let timer = Instant::now();
+        for _ in 0..count {
+            buffer[0] ^= prev_hash as u8;
+            prev_hash = calc::<H>(black_box(&buffer));
+        }
+        values.push(1e-6 * (count * bytes) as f64 / timer.elapsed().as_secs_f64());
+    }
+    black_box(prev_hash);
+    let dependent = summarize(&values, robust).0;
+    let independent = summarize(&measure_bandwidth::<H>(&buffer, count, iters), robust).0;
+    let ratio = dependent / independent;
+    eprintln!("    -> {:5.0} Mb/s dependent, {:5.0} Mb/s independent, ratio {:.3}", dependent, independent, ratio);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.6}", name, bytes, count, iters, dependent, independent,
+        ratio)?;
+    Ok(())
+}
+
+/// Measures the avalanche of the final mixing step: for `count` random bodies, writes the body and then
+/// a single last byte with values 0..=255, and computes the mean Hamming distance between hashes
+/// for consecutive values of the last byte. Hashers without finalization (such as FNV) change few bits.
+fn test_finalization_avalanche<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for finalization avalanche", name);
+    let mut summary = Vec::new();
+    for body_length in [0, 7, 15, 63] {
+        let mut body = vec![0; body_length];
+        let mut total_distance = 0_u64;
+        for _ in 0..count {
+            rng.fill(&mut body[..]);
+            let hashes: Vec<u64> = (0..=255_u8).map(|last_byte| {
+                let mut hasher = H::default();
+                hasher.write(&body);
+                hasher.write(&[last_byte]);
+                hasher.finish()
+            }).collect();
+            total_distance += hashes.windows(2).map(|w| u64::from((w[0] ^ w[1]).count_ones())).sum::<u64>();
+        }
+        let mean_distance = total_distance as f64 / (255 * count) as f64;
+        writeln!(writer, "{}\t{}\t{}\t{:.6}", name, body_length, count, mean_distance)?;
+        summary.push(format!("{:.3}", mean_distance));
+    }
+    eprintln!("    -> mean Hamming distances {}", summary.join(", "));
+    Ok(())
+}
+
+/// Hashes all 1-byte inputs, all 2-byte inputs, and every 251st 3-byte input, and computes mean and standard deviation
+/// of hash values, scaled to [0, 1). Uniform outputs have mean 0.5 and SD 0.2887; `z_mean` shows how far the mean
+/// is from 0.5 (in standard errors), large values indicate that outputs of short inputs are biased toward 0 or MAX.
+fn test_small_input_range<H>(
+    name: &str,
+    bits: u32,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for output range on small inputs", name);
+    let scale = 0.5_f64.powi(bits.min(64) as i32);
+    let mut summary = Vec::new();
+    for (length, step) in [(1, 1), (2, 1), (3, 251)] {
+        let values: Vec<f64> = (0..1_u32 << (8 * length)).step_by(step)
+            .map(|x| calc::<H>(&x.to_le_bytes()[..length]) as f64 * scale)
+            .collect();
+        let (mean, var) = mean_variance(&values);
+        let z_mean = (mean - 0.5) / (1.0 / 12.0_f64).sqrt() * (values.len() as f64).sqrt();
+        writeln!(writer, "{}\t{}\t{}\t{:.6}\t{:.6}\t{:.3}", name, length, values.len(), mean, var.sqrt(), z_mean)?;
+        summary.push(format!("{:.3} ({:.3})", mean, var.sqrt()));
+    }
+    eprintln!("    -> mean (SD) for 1, 2, 3 bytes: {}", summary.join(", "));
+    Ok(())
+}
+
+/// Flips a random half of the input bits (exactly `4 * length` bits) in `count` random inputs,
+/// and reports the distribution of the number of changed output bits.
+/// Output of a good hasher is independent of the original, so the mean is `bits / 2` with SD `sqrt(bits) / 2`.
+fn test_half_avalanche<H>(
+    name: &str,
+    bits: u32,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    assert!(count > 0, "Half avalanche test requires at least one input");
+    eprintln!("Testing {} for half avalanche, length {}", name, length);
+    let timer = Instant::now();
+    let mut buffer = vec![0; length];
+    let mut distances = Vec::with_capacity(count);
+    for _ in 0..count {
+        rng.fill(&mut buffer[..]);
+        let hash0 = calc::<H>(&buffer);
+        for i in rand::seq::index::sample(rng, 8 * length, 4 * length) {
+            buffer[i / 8] ^= 1 << (i % 8);
+        }
+        distances.push(f64::from((calc::<H>(&buffer) ^ hash0).count_ones()));
+    }
+    let (mean, var) = mean_variance(&distances);
+    distances.sort_unstable_by(f64::total_cmp);
+    let [p1, p50, p99] = [1.0, 50.0, 99.0].map(|p| stats::percentile(&distances, p));
+    writeln!(writer, "{}\t{}\t{}\t{}\t{:.6}\t{:.6}\t{}\t{}\t{}\t{}\t{}", name, length, count, bits, mean, var.sqrt(),
+        distances[0], p1, p50, p99, distances[count - 1])?;
+    eprintln!("    -> {:.2} s, mean {:.3}, SD {:.3} (expected {}, {:.3})", timer.elapsed().as_secs_f64(),
+        mean, var.sqrt(), bits / 2, f64::from(bits).sqrt() / 2.0);
+    Ok(())
+}
+
+/// Hashes `count` random inputs, and for each byte of the hash value (only `bits / 8` lowest bytes are used),
+/// counts frequencies of all 256 byte values. Reports chi-squared statistic against the uniform distribution
+/// (255 d.f.) and its p-value for each byte position separately.
+fn test_output_byte_frequency<H>(
+    name: &str,
+    bits: u32,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for output byte frequency, length {}", name, length);
+    let timer = Instant::now();
+    let n_bytes = (bits.min(64) / 8) as usize;
+    let mut buffer = vec![0; length];
+    let mut byte_counts = vec![[0_u64; 256]; n_bytes];
+    for _ in 0..count {
+        rng.fill(&mut buffer[..]);
+        let hash_bytes = calc::<H>(&buffer).to_le_bytes();
+        for (counts, &b) in byte_counts.iter_mut().zip(&hash_bytes) {
+            counts[usize::from(b)] += 1;
+        }
+    }
+    let mut min_p_value = 1.0_f64;
+    for (pos, counts) in byte_counts.iter().enumerate() {
+        let chi2 = stats::chi_squared_uniform(counts);
+        let p_value = stats::chi_squared_p_value(chi2, 255.0);
+        min_p_value = min_p_value.min(p_value);
+        writeln!(writer, "{}\t{}\t{}\t{}\t{:.3}\t{:.6}", name, length, count, pos, chi2, p_value)?;
+    }
+    eprintln!("    -> {:.2} s, min p-value {:.4}", timer.elapsed().as_secs_f64(), min_p_value);
+    Ok(())
+}
+
+/// Separates the cost of hasher construction from hashing: measures time (ns) of `H::default()` alone,
+/// and of `write` + `finish` on 4- and 64-byte inputs using hashers that were constructed in advance.
+/// Hashers with expensive construction are penalized when many short-lived hashers are used.
+/// Times are averaged with mean, or with median if `robust` is true.
+fn benchmark_init_cost<H>(
+    name: &str,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Running {} to measure hasher construction cost", name);
+    let buffer = [15_u8; 64];
+    let mut init_values = Vec::with_capacity(iters);
+    for _ in 0..iters {
+        let timer = Instant::now();
+        for _ in 0..count {
+            black_box(H::default());
+        }
+        init_values.push(1e9 * timer.elapsed().as_secs_f64() / count as f64);
+    }
+    let measure_hash = |bytes: usize| {
+        let mut values = Vec::with_capacity(iters);
+        for _ in 0..iters {
+            let mut hashers: Vec<H> = (0..count).map(|_| H::default()).collect();
+            let timer = Instant::now();
+            for hasher in hashers.iter_mut() {
+                hasher.write(black_box(&buffer[..bytes]));
+                black_box(hasher.finish());
+            }
+            values.push(1e9 * timer.elapsed().as_secs_f64() / count as f64);
+        }
+        summarize(&values, robust).0
+    };
+    let init_ns = summarize(&init_values, robust).0;
+    let hash_ns_4b = measure_hash(4);
+    let hash_ns_64b = measure_hash(64);
+    eprintln!("    -> init {:.2} ns, hash 4 bytes {:.2} ns, hash 64 bytes {:.2} ns", init_ns, hash_ns_4b, hash_ns_64b);
+    writeln!(writer, "{}\t{}\t{}\t{:.4}\t{:.4}\t{:.4}", name, count, iters, init_ns, hash_ns_4b, hash_ns_64b)?;
+    Ok(())
+}
+
+/// Measures bandwidth when the hasher is used through `&mut dyn Hasher`, and compares it with the
+/// monomorphized hasher (same as `evaluate`). The trait object is passed through `black_box`,
+/// so that the compiler cannot devirtualize `write` and `finish` calls.
+fn evaluate_dyn<H>(
+    name: &str,
+    bytes: usize,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Running {} on {} bytes through dyn Hasher", name, bytes);
+    let buffer = vec![15; bytes];
+    let mut values = Vec::with_capacity(iters);
+    for _ in 0..iters {
+        let timer = Instant::now();
+        for _ in 0..count {
+            let mut hasher = H::default();
+            let hasher_ref: &mut dyn Hasher = black_box(&mut hasher);
+            hasher_ref.write(black_box(&buffer));
+            black_box(hasher_ref.finish());
+        }
+        values.push(1e-6 * (count * bytes) as f64 / timer.elapsed().as_secs_f64());
+    }
+    let (dyn_bw, sd) = summarize(&values, robust);
+    let static_bw = summarize(&measure_bandwidth::<H>(&buffer, count, iters), robust).0;
+    // Difference in time per hash, in ns.
+    let overhead_ns = 1e3 * bytes as f64 * (1.0 / dyn_bw - 1.0 / static_bw);
+    eprintln!("    -> {:5.0}±{:5.0} Mb/s, static {:5.0} Mb/s, overhead {:.2} ns", dyn_bw, sd, static_bw,
+        overhead_ns);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.10}\t{:.4}", name, bytes, count, iters,
+        dyn_bw, sd, static_bw, overhead_ns)?;
+    Ok(())
+}
+
+/// Inserts `n_keys` random 16-byte keys into `table_size` buckets (bucket index = `hash % table_size`),
+/// and reports the maximal and the 99th percentile bucket depth.
+/// For a uniform hash, depths follow Poisson distribution with mean `n_keys / table_size`.
+fn test_bucket_depth<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    n_keys: usize,
+    table_size: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    let load_factor = n_keys as f64 / table_size as f64;
+    eprintln!("Testing {} for bucket depth, load factor {}", name, load_factor);
+    let mut buffer = [0_u8; 16];
+    let mut depths = vec![0_u32; table_size];
+    for _ in 0..n_keys {
+        rng.fill(&mut buffer);
+        depths[(calc::<H>(&buffer) % table_size as u64) as usize] += 1;
+    }
+    let mut depths: Vec<f64> = depths.into_iter().map(f64::from).collect();
+    depths.sort_unstable_by(f64::total_cmp);
+    let p99 = stats::percentile(&depths, 99.0);
+    let max_depth = depths[table_size - 1];
+    writeln!(writer, "{}\t{}\t{}\t{:.3}\t{}\t{}", name, n_keys, table_size, load_factor, p99, max_depth)?;
+    eprintln!("    -> p99 depth {}, max depth {}", p99, max_depth);
+    Ok(())
+}
+
+/// Typical struct, used as a cache key.
+#[cfg(feature = "serde-test")]
+#[derive(serde::Serialize)]
+struct SampleRecord {
+    id: u64,
+    name: [u8; 16],
+    score: f32,
+}
+
+#[cfg(feature = "serde-test")]
+impl std::hash::Hash for SampleRecord {
+    fn hash<S: Hasher>(&self, state: &mut S) {
+        self.id.hash(state);
+        self.name.hash(state);
+        self.score.to_bits().hash(state);
+    }
+}
+
+/// Compares time (ns per record) of hashing `count` random `SampleRecord`s, serialized with `bincode::serialize`,
+/// with time of calling `Hash::hash` on the same records directly.
+/// Times are averaged with mean, or with median if `robust` is true.
+#[cfg(feature = "serde-test")]
+fn evaluate_serde_hash<H>(
+    name: &str,
+    count: usize,
+    iters: usize,
+    robust: bool,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    use std::hash::Hash;
+
+    eprintln!("Running {} on bincode-serialized records", name);
+    let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0);
+    let records: Vec<SampleRecord> = (0..count).map(|_| SampleRecord {
+        id: rng.gen(),
+        name: rng.gen(),
+        score: rng.gen(),
+    }).collect();
+    let measure = |f: &dyn Fn(&SampleRecord) -> u64| {
+        let mut values = Vec::with_capacity(iters);
+        for _ in 0..iters {
+            let timer = Instant::now();
+            for record in &records {
+                black_box(f(black_box(record)));
+            }
+            values.push(1e9 * timer.elapsed().as_secs_f64() / count as f64);
+        }
+        summarize(&values, robust).0
+    };
+    let serde_ns = measure(&|record| calc::<H>(&bincode::serialize(record).unwrap()));
+    let direct_ns = measure(&|record| {
+        let mut hasher = H::default();
+        record.hash(&mut hasher);
+        hasher.finish()
+    });
+    eprintln!("    -> serialized {:.2} ns, direct {:.2} ns per record", serde_ns, direct_ns);
+    writeln!(writer, "{}\t{}\t{}\t{:.4}\t{:.4}\t{:.4}", name, count, iters, serde_ns, direct_ns,
+        serde_ns / direct_ns)?;
+    Ok(())
+}
+
+/// Measures aggregate bandwidth of 1, 2, 4, 8 and `max_threads` rayon threads, each hashing its own
+/// `bytes`-byte buffer `count` times, and compares it with the single-thread bandwidth:
+/// efficiency = aggregate bandwidth / (threads * single-thread bandwidth) * 100%.
+/// Hashers that saturate shared resources (execution units of sibling hyper-threads, memory bandwidth)
+/// scale sub-linearly.
+fn evaluate_scaling<H>(
+    name: &str,
+    bytes: usize,
+    max_threads: usize,
+    count: usize,
+    iters: usize,
+    config: &Config,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Running {} on {} bytes with up to {} threads", name, bytes, max_threads);
+    let mut thread_counts: Vec<usize> = [1, 2, 4, 8].into_iter().filter(|&n| n < max_threads).collect();
+    thread_counts.push(max_threads);
+    let mut single_bw = f64::NAN;
+    let mut summary = Vec::new();
+    for threads in thread_counts {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+        // Per-thread buffers are allocated outside of the timed region.
+        let buffers: Vec<Vec<u8>> = (0..threads).map(|_| vec![15_u8; bytes]).collect();
+        let mut values = Vec::with_capacity(iters);
+        for _ in 0..iters {
+            let timer = Instant::now();
+            pool.broadcast(|ctx| {
+                let buffer = &buffers[ctx.index()];
+                for _ in 0..count {
+                    black_box(calc::<H>(black_box(buffer)));
+                }
+            });
+            values.push(1e-6 * (threads * count * bytes) as f64 / timer.elapsed().as_secs_f64());
+        }
+        let (aggregate_bw, sd) = summarize(&values, config.robust_stats);
+        if threads == 1 {
+            single_bw = aggregate_bw;
+        }
+        let efficiency = 100.0 * aggregate_bw / (threads as f64 * single_bw);
+        writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.10}\t{:.10}\t{:.3}", name, bytes, count, iters, threads,
+            aggregate_bw, sd, efficiency)?;
+        summary.push(format!("{}: {:.0}%", threads, efficiency));
+    }
+    eprintln!("    -> efficiency {}", summary.join(", "));
+    Ok(())
+}
+
+/// Hashes the same random 32-byte input with `count` hashers, created by `H::default()` one after another,
+/// and counts distinct hash values. Deterministic hashers produce a single value, randomly seeded hashers
+/// produce `count` distinct values; anything in between suggests that state is shared between instances.
+fn test_reset_independence<H>(
+    name: &str,
+    rng: &mut impl Rng,
+    count: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for independence of default instances", name);
+    let input: [u8; 32] = rng.gen();
+    let mut hashes: Vec<u64> = (0..count).map(|_| calc::<H>(&input)).collect();
+    hashes.sort_unstable();
+    hashes.dedup();
+    let distinct = hashes.len();
+    let verdict = match distinct {
+        1 => "deterministic",
+        _ if distinct == count => "random",
+        _ => "partial",
+    };
+    writeln!(writer, "{}\t{}\t{}\t{}", name, count, distinct, verdict)?;
+    eprintln!("    -> {} distinct values ({})", distinct, verdict);
+    Ok(())
+}
+
+/// Counts hash values of `count` random inputs that fall into the top 1% of the output range
+/// (`>= MAX * 99 / 100`, where MAX is the largest `bits`-bit value). Expected fraction is 1%,
+/// 95% Clopper-Pearson interval that excludes 0.01 indicates a bias near the end of the range.
+fn test_high_value_uniformity<H>(
+    name: &str,
+    bits: u32,
+    rng: &mut impl Rng,
+    count: usize,
+    length: usize,
+    writer: &mut impl Write,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    eprintln!("Testing {} for uniformity of high values, length {}", name, length);
+    let timer = Instant::now();
+    let max = u64::MAX >> (64 - bits.min(64));
+    let threshold = (u128::from(max) * 99 / 100) as u64;
+    let mut buffer = vec![0; length];
+    let mut high = 0_u64;
+    for _ in 0..count {
+        rng.fill(&mut buffer[..]);
+        high += u64::from(calc::<H>(&buffer) >= threshold);
+    }
+    let fraction = high as f64 / count as f64;
+    let (ci_low, ci_high) = stats::clopper_pearson(high, count as u64, 0.05);
+    writeln!(writer, "{}\t{}\t{}\t{}\t{}\t{:.6}\t{:.6}\t{:.6}", name, bits, length, count, high, fraction, ci_low,
+        ci_high)?;
+    eprintln!("    -> {:.2} s, {:.4}% in the top 1% [{:.4}%, {:.4}%]", timer.elapsed().as_secs_f64(), 100.0 * fraction,
+        100.0 * ci_low, 100.0 * ci_high);
+    Ok(())
+}
+
+/// Output files for all enabled benchmarks.
+#[derive(Default)]
+struct Writers {
+    out_dir: PathBuf,
+    hasher_meta: Option<io::BufWriter<fs::File>>,
+    bandwidth: Option<io::BufWriter<fs::File>>,
+    collisions: Option<io::BufWriter<fs::File>>,
+    randomness: Option<io::BufWriter<fs::File>>,
+    suffix: Option<io::BufWriter<fs::File>>,
+    independence_128: Option<io::BufWriter<fs::File>>,
+    dataset_collisions: Option<io::BufWriter<fs::File>>,
+    dataset_randomness: Option<io::BufWriter<fs::File>>,
+    null_byte: Option<io::BufWriter<fs::File>>,
+    streaming: Option<io::BufWriter<fs::File>>,
+    pipeline_effect: Option<io::BufWriter<fs::File>>,
+    palindrome: Option<io::BufWriter<fs::File>>,
+    simd_sizes: Option<io::BufWriter<fs::File>>,
+    convergence: Option<io::BufWriter<fs::File>>,
+    chained: Option<io::BufWriter<fs::File>>,
+    hash_dos: Option<io::BufWriter<fs::File>>,
+    memory: Option<io::BufWriter<fs::File>>,
+    incremental: Option<io::BufWriter<fs::File>>,
+    prefix_free: Option<io::BufWriter<fs::File>>,
+    ks_test: Option<io::BufWriter<fs::File>>,
+    length_independence: Option<io::BufWriter<fs::File>>,
+    stability_iters: Option<io::BufWriter<fs::File>>,
+    distribution_mod: Option<io::BufWriter<fs::File>>,
+    cycles: Option<io::BufWriter<fs::File>>,
+    xor_combine: Option<io::BufWriter<fs::File>>,
+    mass_insert: Option<io::BufWriter<fs::File>>,
+    lookup: Option<io::BufWriter<fs::File>>,
+    clone_cost: Option<io::BufWriter<fs::File>>,
+    sac: Option<io::BufWriter<fs::File>>,
+    block_boundaries: Option<io::BufWriter<fs::File>>,
+    output_range: Option<io::BufWriter<fs::File>>,
+    cache_miss: Option<io::BufWriter<fs::File>>,
+    /// Random pool for `evaluate_cache_miss`, shared by all hashers.
+    cache_miss_pool: Vec<u8>,
+    sorted_vs_shuffled: Option<io::BufWriter<fs::File>>,
+    compressibility: Option<io::BufWriter<fs::File>>,
+    collisions_long: Option<io::BufWriter<fs::File>>,
+    randomness_by_position: Option<io::BufWriter<fs::File>>,
+    alignment_sweep: Option<io::BufWriter<fs::File>>,
+    files: Option<io::BufWriter<fs::File>>,
+    variant_position: Option<io::BufWriter<fs::File>>,
+    empty_input: Option<io::BufWriter<fs::File>>,
+    zero_suffix: Option<io::BufWriter<fs::File>>,
+    write_overhead: Option<io::BufWriter<fs::File>>,
+    pipeline_depth: Option<io::BufWriter<fs::File>>,
+    entropy: Option<io::BufWriter<fs::File>>,
+    parity: Option<io::BufWriter<fs::File>>,
+    bulk_vs_chunks: Option<io::BufWriter<fs::File>>,
+    sibling_collisions: Option<io::BufWriter<fs::File>>,
+    length_autocorrelation: Option<io::BufWriter<fs::File>>,
+    icache: Option<io::BufWriter<fs::File>>,
+    randomness_structured: Option<io::BufWriter<fs::File>>,
+    combine: Option<io::BufWriter<fs::File>>,
+    bit_transitions: Option<io::BufWriter<fs::File>>,
+    preimage: Option<io::BufWriter<fs::File>>,
+    runs_test: Option<io::BufWriter<fs::File>>,
+    seeded_security: Option<io::BufWriter<fs::File>>,
+    data_dependent: Option<io::BufWriter<fs::File>>,
+    finalization: Option<io::BufWriter<fs::File>>,
+    small_input_range: Option<io::BufWriter<fs::File>>,
+    half_avalanche: Option<io::BufWriter<fs::File>>,
+    byte_frequency: Option<io::BufWriter<fs::File>>,
+    init_cost: Option<io::BufWriter<fs::File>>,
+    dyn_dispatch: Option<io::BufWriter<fs::File>>,
+    bucket_depth: Option<io::BufWriter<fs::File>>,
+    #[cfg(feature = "serde-test")]
+    serde_hash: Option<io::BufWriter<fs::File>>,
+    scaling: Option<io::BufWriter<fs::File>>,
+    reset_independence: Option<io::BufWriter<fs::File>>,
+    export_vectors: Option<io::BufWriter<fs::File>>,
+    verify_vectors: Option<vectors::Reference>,
+    high_values: Option<io::BufWriter<fs::File>>,
+}
+
+impl Writers {
+    fn new(out_dir: &Path, config: &Config) -> io::Result<Self> {
+        let mut writers = Self {
+            out_dir: out_dir.to_owned(),
+            hasher_meta: Some(create_writer(out_dir, "hasher_meta.csv", "hasher\tbits\tcpu_microarch")?),
+            ..Self::default()
+        };
+        if config.bandwidth {
+            writers.bandwidth = Some(create_writer(out_dir, "bandwidth.csv",
+                "hasher\tbits\tbytes\tcount\titers\tbandwidth_mean\tbandwidth_sd\tmad\trobust\ttrim_fraction\t\
+                p50\tp90\tp95\tp99")?);
+        }
+        if config.collisions {
+            writers.collisions = Some(create_writer(out_dir, "collisions.csv",
+                "hasher\tbits\tbytes\tvar_start\tvar_end\tcollisions\tcount\tci_low\tci_high")?);
+        }
+        if config.randomness {
+            writers.randomness = Some(create_writer(out_dir, "randomness.csv",
+                "hasher\tbits\tbytes\tchanged_bits\trandomness")?);
+        }
+        if config.suffix_test {
+            writers.suffix = Some(create_writer(out_dir, "suffix_collisions.csv",
+                "hasher\tbytes\tsuffix\tcollisions\tcount")?);
+        }
+        if config.independence_128 {
+            writers.independence_128 = Some(create_writer(out_dir, "independence_128.csv",
+                "hasher\tlength\tpearson_hi_lo")?);
+        }
+        if config.null_byte {
+            writers.null_byte = Some(create_writer(out_dir, "null_byte.csv",
+                "hasher\tnull_position\thash_value")?);
+        }
+        if config.any_dataset() {
+            writers.dataset_collisions = Some(create_writer(out_dir, "dataset_collisions.csv",
+                "hasher\tdataset\tcollisions\tcount")?);
+            writers.dataset_randomness = Some(create_writer(out_dir, "dataset_randomness.csv",
+                "hasher\tbits\tdataset\tchanged_bits\trandomness")?);
+        }
+        if config.streaming {
+            writers.streaming = Some(create_writer(out_dir, "streaming_consistency.csv",
+                "hasher\tlength\tchunk_size\tmismatch_count")?);
+        }
+        if config.pipeline_effect {
+            writers.pipeline_effect = Some(create_writer(out_dir, "pipeline_effect.csv",
+                "hasher\tbytes\tcount\titers\tbandwidth_mean\tbandwidth_sd")?);
+        }
+        if config.palindrome {
+            writers.palindrome = Some(create_writer(out_dir, "palindrome.csv",
+                "hasher\tlength\tequal_count\ttotal\tequality_rate")?);
+        }
+        if config.simd_sizes {
+            writers.simd_sizes = Some(create_writer(out_dir, "simd_sizes.csv",
+                "hasher\tsize_range\tbytes\tcount\titers\tbandwidth_mean\tbandwidth_sd")?);
+        }
+        if config.convergence {
+            writers.convergence = Some(create_writer(out_dir, "convergence.csv",
+                "hasher\tlength\tmean_bytes\tmedian_bytes\tnot_converged\tcount")?);
+        }
+        if config.chained {
+            writers.chained = Some(create_writer(out_dir, "chained.csv",
+                "hasher\tbytes\tdepth\tcount\titers\tchain_ns_mean\tchain_ns_sd")?);
+        }
+        if config.hash_dos {
+            writers.hash_dos = Some(create_writer(out_dir, "hash_dos.csv",
+                "hasher\tattempts\tpairs_found\tsuccess\tseconds")?);
+        }
+        if config.incremental {
+            writers.incremental = Some(create_writer(out_dir, "incremental.csv",
+                "hasher\tbytes\tchunks\tcount\titers\tbandwidth_mean\tbandwidth_sd\tsingle_shot_mean\tratio")?);
+        }
+        if config.prefix_free {
+            writers.prefix_free = Some(create_writer(out_dir, "prefix_free.csv",
+                "hasher\tcollision_count\ttotal_checks")?);
+        }
+        if config.ks_test {
+            writers.ks_test = Some(create_writer(out_dir, "ks_test.csv",
+                "hasher\tlength\tks_statistic\tn")?);
+        }
+        if config.length_independence {
+            writers.length_independence = Some(create_writer(out_dir, "length_independence.csv",
+                "hasher\toriginal_length\tequal_count\ttotal")?);
+        }
+        if config.stability_iters {
+            writers.stability_iters = Some(create_writer(out_dir, "stability_iters.csv",
+                "hasher\tbytes\titers_needed")?);
+        }
+        if config.distribution_mod {
+            writers.distribution_mod = Some(create_writer(out_dir, "distribution_mod.csv",
+                "hasher\tlength\tmodulus\tchi_squared\tcount")?);
+        }
+        if config.cycles {
+            writers.cycles = Some(create_writer(out_dir, "cycles.csv",
+                "hasher\tbytes\tcount\tp50\tp95\tp99")?);
+        }
+        if config.xor_combine {
+            writers.xor_combine = Some(create_writer(out_dir, "xor_combine.csv",
+                "hasher\thalf_length\tcombined_collisions\txor_collisions")?);
+        }
+        if config.mass_insert {
+            writers.mass_insert = Some(create_writer(out_dir, "mass_insert.csv",
+                "hasher\tn_entries\tkey_len\ttotal_ms\tinserts_per_sec")?);
+        }
+        if config.lookup {
+            writers.lookup = Some(create_writer(out_dir, "lookup.csv",
+                "hasher\tn_entries\tkey_len\thits_per_sec\tmisses_per_sec\ttotal_ns_per_op")?);
+        }
+        if config.clone_cost {
+            writers.clone_cost = Some(create_writer(out_dir, "clone_cost.csv",
+                "hasher\tbytes\tcount\titers\tbandwidth_plain\tbandwidth_clone\tratio")?);
+        }
+        if config.sac {
+            writers.sac = Some(create_writer(out_dir, "sac.csv",
+                "hasher\tlength\tmean_deviation\tmax_deviation\tcount")?);
+        }
+        if config.block_boundaries {
+            writers.block_boundaries = Some(create_writer(out_dir, "block_boundaries.csv",
+                "hasher\tblock_size\tbytes\tis_boundary\tbandwidth_mean\tbandwidth_sd")?);
+        }
+        if config.memory {
+            writers.memory = Some(create_writer(out_dir, "memory.csv",
+                "hasher\tpass\trss_before_bytes\trss_after_bytes\tpeak_rss_bytes")?);
+        }
+        if config.output_range {
+            writers.output_range = Some(create_writer(out_dir, "output_range.csv",
+                "hasher\tmin\tmax\ttop_bit_fraction\tprefix\tprefix_fraction")?);
+        }
+        if config.cache_miss {
+            writers.cache_miss = Some(create_writer(out_dir, "cache_miss.csv",
+                "hasher\tpool_size_mib\tinput_bytes\tcount\titers\tbandwidth_pool\tbandwidth_cached\tratio")?);
+            writers.cache_miss_pool = vec![0; 1024 * 1024 * 1024];
+            rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0).fill(&mut writers.cache_miss_pool[..]);
+        }
+        if config.sorted_vs_shuffled {
+            writers.sorted_vs_shuffled = Some(create_writer(out_dir, "sorted_vs_shuffled.csv",
+                "hasher\tlength\tsorted_collisions\tshuffled_collisions")?);
+        }
+        if config.compressibility {
+            writers.compressibility = Some(create_writer(out_dir, "compressibility.csv",
+                "hasher\tlength\tcount\trle_ratio")?);
+        }
+        if config.collisions_long {
+            writers.collisions_long = Some(create_writer(out_dir, "collisions_long.csv",
+                "hasher\tbits\tbytes\tvar_start\tvar_end\tcollisions\tcount\tci_low\tci_high")?);
+        }
+        if config.randomness_by_position {
+            writers.randomness_by_position = Some(create_writer(out_dir, "randomness_by_position.csv",
+                "hasher\tlength\tbyte_position\tavg_changed_bits")?);
+        }
+        if config.alignment_sweep {
+            writers.alignment_sweep = Some(create_writer(out_dir, "alignment_sweep.csv",
+                "hasher\tbytes\toffset\taddress_mod_64\tbandwidth_mean\tbandwidth_sd")?);
+        }
+        if config.file_dir.is_some() {
+            writers.files = Some(create_writer(out_dir, "files.csv",
+                "hasher\tfile\tbytes\tcount\titers\tbandwidth_mean\tbandwidth_sd")?);
+        }
+        if config.variant_position {
+            writers.variant_position = Some(create_writer(out_dir, "variant_position.csv",
+                "hasher\tlength\tvariant\tvar_start\tcollisions\tcount")?);
+        }
+        if config.empty_input {
+            writers.empty_input = Some(create_writer(out_dir, "empty_input.csv",
+                "hasher\tempty_hash\tzero_byte_hash\tequal\tdegenerate")?);
+        }
+        if config.zero_suffix {
+            writers.zero_suffix = Some(create_writer(out_dir, "zero_suffix.csv",
+                "hasher\tbase_length\tzero_suffix_len\tequal_count")?);
+        }
+        if config.write_overhead {
+            writers.write_overhead = Some(create_writer(out_dir, "write_overhead.csv",
+                "hasher\ttotal_bytes\twrite_size\tcount\titers\tbandwidth_mean\tbandwidth_sd\tsingle_write\tratio")?);
+        }
+        if config.pipeline_depth {
+            writers.pipeline_depth = Some(create_writer(out_dir, "pipeline_depth.csv",
+                "hasher\tbytes\tpipeline_depth\tcount\titers\tbandwidth_mean\tbandwidth_sd")?);
+        }
+        if config.entropy {
+            writers.entropy = Some(create_writer(out_dir, "entropy.csv",
+                "hasher\tbytes\tentropy_level\trandom_bytes\tcount\titers\tbandwidth_mean\tbandwidth_sd")?);
+        }
+        if config.parity {
+            writers.parity = Some(create_writer(out_dir, "parity.csv",
+                "hasher\tlength\tparity_0_count\tparity_1_count\tchi2")?);
+        }
+        if config.bulk_vs_chunks {
+            writers.bulk_vs_chunks = Some(create_writer(out_dir, "bulk_vs_chunks.csv",
+                "hasher\ttotal_bytes\tchunk_size\tcount\titers\tbandwidth_bulk\tbandwidth_chunked\tefficiency_ratio")?);
+        }
+        if config.sibling_collisions {
+            writers.sibling_collisions = Some(create_writer(out_dir, "sibling_collisions.csv",
+                "hasher\ttotal_len\tdiffer_at\tcollisions\tcount\tcollision_rate")?);
+        }
+        if config.length_autocorrelation {
+            writers.length_autocorrelation = Some(create_writer(out_dir, "length_autocorrelation.csv",
+                "hasher\tlag\tautocorrelation")?);
+        }
+        if config.icache {
+            writers.icache = Some(create_writer(out_dir, "icache.csv",
+                "hasher\tbytes\teviction_size_kb\tcount\titers\tthroughput_cold\tthroughput_warm\tratio")?);
+        }
+        if config.randomness_structured {
+            writers.randomness_structured = Some(create_writer(out_dir, "randomness_structured.csv",
+                "hasher\tbits\tbytes\tchanged_bits\trandomness")?);
+        }
+        if config.combine {
+            writers.combine = Some(create_writer(out_dir, "combine.csv",
+                "hasher\tbytes_per_field\tn_fields\tcount\titers\tbandwidth_combined\tbandwidth_single\toverhead_ns")?);
+        }
+        if config.bit_transitions {
+            writers.bit_transitions = Some(create_writer(out_dir, "bit_transitions.csv",
+                "hasher\tlength\ttransition\tcount\tfrequency")?);
+        }
+        if config.preimage {
+            writers.preimage = Some(create_writer(out_dir, "preimage.csv",
+                "hasher\tbits\ttargets\tfound\tmean_trials\texpected_trials")?);
+        }
+        if config.runs_test {
+            writers.runs_test = Some(create_writer(out_dir, "runs_test.csv",
+                "hasher\tlength\tcount\tz\tp_value")?);
+        }
+        if config.seeded_security {
+            writers.seeded_security = Some(create_writer(out_dir, "seeded_security.csv",
+                "hasher\tlength\tn_seeds\tcount\tmean_collision_rate\tseed_variance_of_collision_rate")?);
+        }
+        if config.data_dependent {
+            writers.data_dependent = Some(create_writer(out_dir, "data_dependent.csv",
+                "hasher\tbytes\tcount\titers\tbandwidth_dependent\tbandwidth_independent\tratio")?);
+        }
+        if config.finalization {
+            writers.finalization = Some(create_writer(out_dir, "finalization.csv",
+                "hasher\tbody_length\tcount\tmean_distance")?);
+        }
+        if config.small_input_range {
+            writers.small_input_range = Some(create_writer(out_dir, "small_input_range.csv",
+                "hasher\tlength\tcount\tmean\tsd\tz_mean")?);
+        }
+        if config.half_avalanche {
+            writers.half_avalanche = Some(create_writer(out_dir, "half_avalanche.csv",
+                "hasher\tlength\tcount\tbits\tmean\tsd\tmin\tp1\tp50\tp99\tmax")?);
+        }
+        if config.byte_frequency {
+            writers.byte_frequency = Some(create_writer(out_dir, "byte_frequency.csv",
+                "hasher\tlength\tcount\tbyte_pos\tchi2\tp_value")?);
+        }
+        if config.init_cost {
+            writers.init_cost = Some(create_writer(out_dir, "init_cost.csv",
+                "hasher\tcount\titers\tinit_ns\thash_ns_4b\thash_ns_64b")?);
+        }
+        if config.dyn_dispatch {
+            writers.dyn_dispatch = Some(create_writer(out_dir, "dyn_dispatch.csv",
+                "hasher\tbytes\tcount\titers\tbandwidth_dyn\tsd_dyn\tbandwidth_static\toverhead_ns")?);
+        }
+        if config.bucket_depth {
+            writers.bucket_depth = Some(create_writer(out_dir, "bucket_depth.csv",
+                "hasher\tn_keys\ttable_size\tload_factor\tp99_depth\tmax_depth")?);
+        }
+        #[cfg(feature = "serde-test")]
+        if config.serde_hash {
+            writers.serde_hash = Some(create_writer(out_dir, "serde_hash.csv",
+                "hasher\tcount\titers\tserde_ns\tdirect_ns\tratio")?);
+        }
+        if config.scaling {
+            writers.scaling = Some(create_writer(out_dir, "scaling.csv",
+                "hasher\tbytes\tcount\titers\tthreads\taggregate_bw_mb_s\tsd\tefficiency_pct")?);
+        }
+        if config.reset_independence {
+            writers.reset_independence = Some(create_writer(out_dir, "reset_independence.csv",
+                "hasher\tcount\tdistinct\tverdict")?);
+        }
+        if let Some(path) = config.export_vectors.as_ref() {
+            writers.export_vectors = Some(io::BufWriter::new(fs::File::create(path)?));
+        }
+        if let Some(path) = config.verify_vectors.as_ref() {
+            writers.verify_vectors = Some(vectors::Reference::load(path)?);
+        }
+        if config.high_values {
+            writers.high_values = Some(create_writer(out_dir, "high_values.csv",
+                "hasher\tbits\tlength\tcount\thigh\tfraction\tci_low\tci_high")?);
+        }
+        Ok(writers)
+    }
+}
+
+/// Creates CSV file in the output directory and writes the header.
+fn create_writer(out_dir: &Path, filename: &str, header: &str) -> io::Result<io::BufWriter<fs::File>> {
+    report::create_table(&out_dir.join(filename), header)
+}
+
+/// Runs collision and randomness tests on the dataset.
+fn test_dataset<H>(
+    name: &str,
+    bits: u32,
+    dataset: &str,
+    inputs: &[impl AsRef<[u8]>],
+    writers: &mut Writers,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    if let Some(writer) = writers.dataset_collisions.as_mut() {
+        test_dataset_collisions::<H>(name, dataset, inputs, writer)?;
+    }
+    if let Some(writer) = writers.dataset_randomness.as_mut() {
+        test_dataset_randomness::<H>(name, bits, dataset, inputs, writer)?;
+    }
+    Ok(())
+}
+
+/// Runs collision and randomness tests on all enabled generated datasets.
+fn test_datasets<H>(
+    name: &str,
+    bits: u32,
+    config: &Config,
+    writers: &mut Writers,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    const COUNT: usize = 2_usize.pow(22);
+    if config.timestamps {
+        test_dataset::<H>(name, bits, "timestamps", &gen::timestamp_like(COUNT), writers)?;
+    }
+    if config.alternating_bits {
+        test_dataset::<H>(name, bits, "alternating_bits_16", &gen::alternating_bits::<16>(1000), writers)?;
+        test_dataset::<H>(name, bits, "alternating_bits_64", &gen::alternating_bits::<64>(1000), writers)?;
+    }
+    if config.power_of_two {
+        for inputs in gen::power_of_two_lengths::<16>(2_usize.pow(10), 2_usize.pow(20)) {
+            test_dataset::<H>(name, bits, &format!("power_of_two_{}", inputs.len()), &inputs, writers)?;
+        }
+    }
+    if config.network_packets {
+        let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0);
+        for payload_size in [46, 128] {
+            let inputs = gen::network_packets(&mut rng, 2_usize.pow(18), payload_size);
+            test_dataset::<H>(name, bits, &format!("network_packets_{}", payload_size), &inputs, writers)?;
+        }
+    }
+    if config.hex_sha256 {
+        let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0);
+        let digests = gen::hex_sha256_like(&mut rng, 2_usize.pow(20));
+        test_dataset::<H>(name, bits, "hex_sha256", &digests, writers)?;
+        // Shared prefix of 56 characters, only the last 8 characters vary.
+        let shared_prefix: Vec<[u8; 64]> = (0..digests.len() as u64).map(|i| {
+            let mut arr = digests[0];
+            fill_hex(arr[56..].iter_mut().rev(), i);
+            arr.make_ascii_lowercase();
+            arr
+        }).collect();
+        test_dataset::<H>(name, bits, "hex_sha256_shared_prefix", &shared_prefix, writers)?;
+    }
+    if config.struct_padding {
+        let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0);
+        for (dataset, padding) in [("padding_00", Some(0x00)), ("padding_ff", Some(0xff)), ("padding_random", None)] {
+            test_dataset::<H>(name, bits, dataset, &gen::struct_with_padding(&mut rng, COUNT, padding), writers)?;
+        }
+    }
+    if config.base64 {
+        const BASE64_COUNT: usize = 2_usize.pow(20);
+        for decoded_len in [6, 12, 24, 48] {
+            // Same seed produces the same decoded data.
+            let raw = gen::random_binary(&mut rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0), BASE64_COUNT,
+                decoded_len);
+            test_dataset::<H>(name, bits, &format!("raw_{}", decoded_len), &raw, writers)?;
+            let encoded = gen::base64_encoded(&mut rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0), BASE64_COUNT,
+                decoded_len);
+            test_dataset::<H>(name, bits, &format!("base64_{}", decoded_len), &encoded, writers)?;
+        }
+    }
+    if config.kubernetes_pods {
+        let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0);
+        test_dataset::<H>(name, bits, "kubernetes_pods", &gen::kubernetes_pod_names(&mut rng, 2_usize.pow(20)),
+            writers)?;
+    }
+    if config.json_keys {
+        test_dataset::<H>(name, bits, "json_keys", &gen::json_keys(2_usize.pow(20)), writers)?;
+    }
+    if config.salted_keys {
+        let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0);
+        // All 2^16 salts of length 2 are used.
+        for (salt_len, count) in [(2, 2_usize.pow(16)), (4, 2_usize.pow(20)), (8, 2_usize.pow(20))] {
+            let keys = gen::salted_keys::<32>(&mut rng, salt_len, count);
+            test_dataset::<H>(name, bits, &format!("salted_keys_{}", salt_len), &keys, writers)?;
+        }
+    }
+    Ok(())
+}
+
+/// Memory usage at the start of one pass over a hasher (`test_hasher`, `test_hasher128`, ...).
+struct MemoryRecord {
+    rss_before: Option<usize>,
+    /// Peak RSS was reset, so that it only covers this pass (Linux only).
+    peak_reset: bool,
+}
+
+impl MemoryRecord {
+    fn start() -> Self {
+        let peak_reset = platform::reset_peak_rss();
+        Self { rss_before: platform::current_rss_bytes(), peak_reset }
+    }
+
+    /// Writes current RSS before and after the pass, and peak RSS during the pass to `memory.csv`.
+    /// Peak RSS can only be reset on Linux, on other platforms it is written as NA.
+    fn finish(self, name: &str, pass: &str, writers: &mut Writers) -> io::Result<()> {
+        let Some(writer) = writers.memory.as_mut() else {
+            return Ok(());
+        };
+        let rss_after = platform::current_rss_bytes();
+        // Kernel RSS counters are synchronized lazily, so the peak can lag slightly behind the current value.
+        let peak = self.peak_reset.then(platform::peak_rss_bytes).flatten()
+            .map(|peak| peak.max(rss_after.unwrap_or(0)));
+        let fmt = |rss: Option<usize>| rss.map_or_else(|| "NA".to_owned(), |rss| rss.to_string());
+        writeln!(writer, "{}\t{}\t{}\t{}\t{}", name, pass, fmt(self.rss_before), fmt(rss_after), fmt(peak))
+    }
+}
+
+/// Runs all enabled benchmarks for the hasher, `bits` is the number of meaningful bits in `finish` output.
+fn test_hasher<H>(
+    name: &str,
+    bits: u32,
+    mut rng: impl Rng,
+    config: &Config,
+    writers: &mut Writers,
+) -> io::Result<()>
+where H: Hasher + Default,
+{
+    if !config.hasher_enabled(name) {
+        return Ok(());
+    }
+    if let Some(writer) = writers.hasher_meta.as_mut() {
+        writeln!(writer, "{}\t{}\t{}", name, bits, platform::cpu_microarch_name())?;
+    }
+    let memory = config.memory.then(MemoryRecord::start);
+    if let Some(writer) = writers.bandwidth.as_mut() {
+        const ITERS: usize = 1024;
+        if let Some(range) = config.size_range.as_ref() {
+            evaluate_all_sizes::<H>(name, bits, range, 2_usize.pow(14), ITERS, config, writer)?;
+        } else {
+            evaluate::<H>(name, bits, 4, 2_usize.pow(18), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 8, 2_usize.pow(18), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 12, 2_usize.pow(18), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 16, 2_usize.pow(18), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 32, 2_usize.pow(17), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 64, 2_usize.pow(16), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 128, 2_usize.pow(16), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 256, 2_usize.pow(15), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 512, 2_usize.pow(15), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 1024, 2_usize.pow(14), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 2048, 2_usize.pow(14), ITERS, config, writer)?;
+            evaluate::<H>(name, bits, 4096, 2_usize.pow(14), ITERS, config, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.collisions.as_mut() {
+        let count = 2_usize.pow(24);
+        let affix = 6;
+        for size in (8..=32).step_by(2) {
+            // test_collisions::<H>(name, bits, &mut rng, count, size, 0..affix, writer)?;
+            // test_collisions::<H>(name, bits, &mut rng, count, size, 8..8 + affix, writer)?;
+            test_collisions::<H>(name, bits, &mut rng, count, size + affix, size..size + affix, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.randomness.as_mut() {
+        let count = 2_usize.pow(22);
+        for &size in &[8, 12, 16, 20, 24, 28, 32] {
+            test_randomness::<H>(name, bits, &mut rng, count, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.suffix.as_mut() {
+        let count = 2_usize.pow(22);
+        test_suffix_collisions::<H, 16>(name, &mut rng, count, writer)?;
+        test_suffix_collisions::<H, 32>(name, &mut rng, count, writer)?;
+    }
+
+    if let Some(writer) = writers.null_byte.as_mut() {
+        test_null_byte_handling::<H>(name, writer)?;
+    }
+
+    if config.any_dataset() {
+        test_datasets::<H>(name, bits, config, writers)?;
+    }
+
+    if let Some(writer) = writers.streaming.as_mut() {
+        let count = 2_usize.pow(16);
+        for &size in &[16, 64, 256] {
+            test_streaming_consistency::<H>(name, &mut rng, count, size, &[1, 3, 8, 16], writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.pipeline_effect.as_mut() {
+        const ITERS: usize = 1024;
+        const COUNTS: [usize; 8] = [1, 2, 4, 8, 16, 64, 256, 1024];
+        for &size in &[8, 64, 1024] {
+            evaluate_throughput_vs_count::<H>(name, size, &COUNTS, ITERS, config.robust_stats, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.palindrome.as_mut() {
+        test_palindrome_hashing::<H>(name, writer)?;
+    }
+
+    if let Some(writer) = writers.simd_sizes.as_mut() {
+        evaluate_simd_comparison::<H>(name, config.robust_stats, writer)?;
+    }
+
+    if let Some(save) = config.save_hashes.as_ref().filter(|save| save.hasher == name) {
+        save_hashes::<H>(name, &mut rng, save.count, save.size, &writers.out_dir, config.save_inputs)?;
+    }
+
+    if let Some(writer) = writers.convergence.as_mut() {
+        test_avalanche_convergence::<H>(name, bits, &mut rng, 2_usize.pow(20), writer)?;
+    }
+
+    if let Some(writer) = writers.hash_dos.as_mut() {
+        test_hash_dos_resistance::<H>(name, writer)?;
+    }
+
+    if let Some(writer) = writers.incremental.as_mut() {
+        const ITERS: usize = 256;
+        for &size in &[64, 1024, 4096] {
+            evaluate_incremental_size::<H>(name, size, 2_usize.pow(14), ITERS, config.robust_stats, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.prefix_free.as_mut() {
+        test_prefix_collision::<H>(name, &mut rng, 2_usize.pow(16), writer)?;
+    }
+
+    if let Some(writer) = writers.ks_test.as_mut() {
+        let count = 2_usize.pow(20);
+        for &size in &[8, 16, 32, 64] {
+            test_ks_uniformity::<H>(name, bits, &mut rng, count, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.length_independence.as_mut() {
+        test_length_independence::<H>(name, writer)?;
+    }
+
+    if let Some(writer) = writers.stability_iters.as_mut() {
+        for &size in &[8, 64, 1024] {
+            evaluate_convergence::<H>(name, size, 4096, config.robust_stats, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.distribution_mod.as_mut() {
+        // Prime, power of two and round decimal table sizes.
+        const MODULI: [u64; 3] = [1_000_003, 1_048_576, 1_000_000];
+        let count = 2_usize.pow(24);
+        for &size in &[8, 16, 32] {
+            test_distribution_by_mod::<H>(name, &mut rng, count, size, &MODULI, writer)?;
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    if let Some(writer) = writers.cycles.as_mut() {
+        for &size in &[4, 8, 16, 32] {
+            evaluate_cycles::<H>(name, size, 2_usize.pow(20), writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.xor_combine.as_mut() {
+        let count = 2_usize.pow(22);
+        for &size in &[4, 8, 16] {
+            test_xor_combine::<H>(name, &mut rng, count, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.mass_insert.as_mut() {
+        for &size in &[8, 32, 128] {
+            test_mass_insert::<H>(name, &mut rng, 1_000_000, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.lookup.as_mut() {
+        for &size in &[8, 32, 128] {
+            test_lookup_mixed::<H>(name, &mut rng, 1_000_000, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.sac.as_mut() {
+        test_incremental_avalanche::<H>(name, bits, &mut rng, 2_usize.pow(12), &[8, 16, 32, 64, 128], writer)?;
+    }
+
+    if let Some(writer) = writers.block_boundaries.as_mut() {
+        for block_size in [8, 16, 32, 64] {
+            evaluate_around_block_boundaries::<H>(name, block_size, config.robust_stats, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.output_range.as_mut() {
+        test_output_range::<H>(name, bits, &mut rng, 2_usize.pow(22), writer)?;
+    }
+
+    if let Some(writer) = writers.cache_miss.as_mut() {
+        for &size in &[8, 64, 1024] {
+            evaluate_cache_miss::<H>(name, &mut rng, &writers.cache_miss_pool, size, 2_usize.pow(20),
+                config.robust_stats, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.sorted_vs_shuffled.as_mut() {
+        let count = 2_usize.pow(22);
+        for &size in &[8, 16, 32] {
+            test_sorted_inputs::<H>(name, &mut rng, count, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.compressibility.as_mut() {
+        let count = 2_usize.pow(20);
+        for &size in &[8, 16, 32] {
+            test_compressibility::<H>(name, bits, &mut rng, count, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.collisions_long.as_mut() {
+        let count = 2_usize.pow(20);
+        let affix = 6;
+        for size in [64, 128, 256, 512, 1024, 2048, 4096] {
+            test_collisions::<H>(name, bits, &mut rng, count, size, size - affix..size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.randomness_by_position.as_mut() {
+        let count = 2_usize.pow(18);
+        for &size in &[8, 16, 32, 64] {
+            test_randomness_by_position::<H>(name, &mut rng, count, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.alignment_sweep.as_mut() {
+        for &size in &[64, 1024] {
+            evaluate_alignment_sweep::<H>(name, size, 2_usize.pow(14), 64, config.robust_stats, writer)?;
+        }
+    }
+
+    if let Some(dir) = &config.file_dir {
+        let writer = writers.files.as_mut().unwrap();
+        evaluate_files::<H>(name, dir, 16, config.robust_stats, writer)?;
+    }
+
+    if let Some(writer) = writers.variant_position.as_mut() {
+        let count = 2_usize.pow(16);
+        test_variant_position::<H, 8>(name, &mut rng, count, writer)?;
+        test_variant_position::<H, 32>(name, &mut rng, count, writer)?;
+        test_variant_position::<H, 128>(name, &mut rng, count, writer)?;
+    }
+
+    if let Some(writer) = writers.empty_input.as_mut() {
+        test_empty_input::<H>(name, writer)?;
+    }
+
+    if let Some(writer) = writers.zero_suffix.as_mut() {
+        test_zero_suffix_collision::<H>(name, &mut rng, 10_000, writer)?;
+    }
+
+    if let Some(writer) = writers.write_overhead.as_mut() {
+        for write_size in [1, 2, 4, 8, 16] {
+            evaluate_write_call_overhead::<H>(name, 64, write_size, 2_usize.pow(16), 256, config.robust_stats, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.pipeline_depth.as_mut() {
+        for &size in &[64, 4096] {
+            for depth in [1, 2, 4] {
+                evaluate_pipeline::<H>(name, size, depth, 2_usize.pow(18) / size, 256, config.robust_stats, writer)?;
+            }
+        }
+    }
+
+    if let Some(writer) = writers.entropy.as_mut() {
+        for &size in &[64, 1024] {
+            for entropy_level in [0.0, 0.25, 0.5, 0.75, 1.0] {
+                evaluate_vs_entropy::<H>(name, size, entropy_level, 2_usize.pow(18) / size, 256, config.robust_stats,
+                    writer)?;
+            }
+        }
+    }
+
+    if let Some(writer) = writers.parity.as_mut() {
+        let count = 2_usize.pow(20);
+        for &size in &[4, 8, 16, 64] {
+            test_parity_uniformity::<H>(name, &mut rng, count, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.bulk_vs_chunks.as_mut() {
+        evaluate_bulk_vs_chunks::<H>(name, 1024 * 1024, &[64, 1024, 16384], 4, 64, config.robust_stats, writer)?;
+    }
+
+    if let Some(writer) = writers.sibling_collisions.as_mut() {
+        let count = 2_usize.pow(22);
+        for &size in &[8, 16, 32] {
+            for differ_at in [1, 2, 4] {
+                test_sibling_collisions::<H>(name, &mut rng, count, size, differ_at, writer)?;
+            }
+        }
+    }
+
+    if let Some(writer) = writers.length_autocorrelation.as_mut() {
+        test_sequential_length_correlation::<H>(name, &mut rng, 2_usize.pow(16), 32, writer)?;
+    }
+
+    if let Some(writer) = writers.icache.as_mut() {
+        for &size in &[64, 1024] {
+            evaluate_icache::<H>(name, size, 4096, 64, 16, config.robust_stats, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.randomness_structured.as_mut() {
+        let count = 2_usize.pow(20);
+        for &size in &[8, 16, 32] {
+            test_randomness_structured::<H>(name, bits, count, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.combine.as_mut() {
+        for &size in &[8, 32] {
+            for n_fields in [2, 4, 8] {
+                evaluate_combine::<H>(name, size, n_fields, 2_usize.pow(16), 64, config.robust_stats, writer)?;
+            }
+        }
+    }
+
+    if let Some(writer) = writers.bit_transitions.as_mut() {
+        let count = 2_usize.pow(20);
+        for &size in &[4, 8, 16, 64] {
+            test_bit_transitions::<H>(name, bits, &mut rng, count, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.preimage.as_mut() {
+        test_preimage_difficulty::<H>(name, &mut rng, writer)?;
+    }
+
+    if let Some(writer) = writers.runs_test.as_mut() {
+        let count = 2_usize.pow(20);
+        for &size in &[4, 8, 16, 64] {
+            test_runs_randomness::<H>(name, &mut rng, count, size, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.data_dependent.as_mut() {
+        for &size in &[8, 64, 1024] {
+            evaluate_data_dependent::<H>(name, size, 2_usize.pow(18) / size.min(64), 256, config.robust_stats, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.finalization.as_mut() {
+        test_finalization_avalanche::<H>(name, &mut rng, 4096, writer)?;
+    }
+
+    if let Some(writer) = writers.small_input_range.as_mut() {
+        test_small_input_range::<H>(name, bits, writer)?;
+    }
+
+    if let Some(writer) = writers.half_avalanche.as_mut() {
+        for length in [4, 16, 64] {
+            test_half_avalanche::<H>(name, bits, &mut rng, 100_000, length, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.byte_frequency.as_mut() {
+        for length in [4, 16, 64] {
+            test_output_byte_frequency::<H>(name, bits, &mut rng, 1 << 20, length, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.init_cost.as_mut() {
+        benchmark_init_cost::<H>(name, 100_000, 50, config.robust_stats, writer)?;
+    }
+
+    if let Some(writer) = writers.dyn_dispatch.as_mut() {
+        for bytes in [4, 16, 64, 1024] {
+            evaluate_dyn::<H>(name, bytes, 100_000, 20, config.robust_stats, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.bucket_depth.as_mut() {
+        const TABLE_SIZE: usize = 1 << 20;
+        for n_keys in [TABLE_SIZE / 2, TABLE_SIZE * 7 / 8, TABLE_SIZE, 2 * TABLE_SIZE] {
+            test_bucket_depth::<H>(name, &mut rng, n_keys, TABLE_SIZE, writer)?;
+        }
+    }
+
+    #[cfg(feature = "serde-test")]
+    if let Some(writer) = writers.serde_hash.as_mut() {
+        evaluate_serde_hash::<H>(name, 100_000, 20, config.robust_stats, writer)?;
+    }
+
+    if let Some(writer) = writers.scaling.as_mut() {
+        let max_threads = std::thread::available_parallelism().map_or(1, usize::from);
+        for bytes in [64, 4096, 1 << 20] {
+            evaluate_scaling::<H>(name, bytes, max_threads, (1 << 24) / bytes, 10, config, writer)?;
+        }
+    }
+
+    if let Some(writer) = writers.reset_independence.as_mut() {
+        test_reset_independence::<H>(name, &mut rng, 10_000, writer)?;
+    }
+
+    if (writers.export_vectors.is_some() || writers.verify_vectors.is_some()) && !vectors::SKIPPED.contains(&name) {
+        let hashes = vectors::hash_all::<H>(&vectors::inputs());
+        if let Some(writer) = writers.export_vectors.as_mut() {
+            vectors::export(writer, name, &hashes)?;
+        }
+        if let Some(reference) = writers.verify_vectors.as_mut() {
+            reference.verify(name, &hashes)?;
+        }
+    }
+
+    if let Some(writer) = writers.high_values.as_mut() {
+        for length in [4, 16, 64] {
+            test_high_value_uniformity::<H>(name, bits, &mut rng, 1 << 20, length, writer)?;
+        }
+    }
+
+    if let Some(memory) = memory {
+        memory.finish(name, "hasher", writers)?;
+    }
+    eprintln!();
+    Ok(())
+}
+
+/// Runs benchmarks, specific to hashers with 128-bit output.
+fn test_hasher128<H>(
+    name: &str,
+    mut rng: impl Rng,
+    config: &Config,
+    writers: &mut Writers,
+) -> io::Result<()>
+where H: Hasher128,
+{
+    if !config.hasher_enabled(name) {
+        return Ok(());
+    }
+    let memory = config.memory.then(MemoryRecord::start);
+    if let Some(writer) = writers.independence_128.as_mut() {
+        let count = 2_usize.pow(20);
+        for &size in &[8, 16, 32, 64] {
+            test_128bit_independence::<H>(name, &mut rng, count, size, writer)?;
+        }
+        eprintln!();
+    }
+    if let Some(memory) = memory {
+        memory.finish(name, "hasher128", writers)?;
+    }
+    Ok(())
+}
+
+/// Runs benchmarks, specific to hashers that can be constructed from a seed.
+fn test_seedable_hasher<H>(
+    name: &str,
+    config: &Config,
+    writers: &mut Writers,
+) -> io::Result<()>
+where H: SeedableHasher,
+{
+    if !config.hasher_enabled(name) {
+        return Ok(());
+    }
+    let memory = config.memory.then(MemoryRecord::start);
+    if let Some(writer) = writers.chained.as_mut() {
+        const COUNT: usize = 2_usize.pow(12);
+        const ITERS: usize = 256;
+        for &size in &[8, 64, 1024] {
+            for depth in [1, 16] {
+                evaluate_chained::<H>(name, size, depth, COUNT, ITERS, config.robust_stats, writer)?;
+            }
+        }
+        eprintln!();
+    }
+    if let Some(writer) = writers.seeded_security.as_mut() {
+        let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0);
+        for &size in &[8, 64] {
+            test_seeded_security::<H>(name, &mut rng, 32, 2_usize.pow(16), size, writer)?;
+        }
+        eprintln!();
+    }
+    if let Some(memory) = memory {
+        memory.finish(name, "seedable", writers)?;
+    }
+    Ok(())
+}
+
+/// Runs benchmarks, specific to hashers that implement `Clone`.
+fn test_cloneable_hasher<H>(
+    name: &str,
+    config: &Config,
+    writers: &mut Writers,
+) -> io::Result<()>
+where H: Hasher + Default + Clone,
+{
+    if !config.hasher_enabled(name) {
+        return Ok(());
+    }
+    let memory = config.memory.then(MemoryRecord::start);
+    if let Some(writer) = writers.clone_cost.as_mut() {
+        for &size in &[16, 64, 1024] {
+            evaluate_clone_cost::<H>(name, size, 2_usize.pow(14), config.robust_stats, writer)?;
+        }
+        eprintln!();
+    }
+    if let Some(memory) = memory {
+        memory.finish(name, "cloneable", writers)?;
+    }
+    Ok(())
+}
+
+/// Prints size and alignment of the hasher state. States larger than a cache line (64 bytes)
+/// may cause extra cache misses in tight loops. Heap allocations (such as fasthash input buffers) are not included.
+fn print_layout<H>(name: &str, config: &Config) {
+    if config.hasher_enabled(name) {
+        let size = std::mem::size_of::<H>();
+        println!("{}\t{}\t{}\t{}", name, size, std::mem::align_of::<H>(), size <= 64);
+    }
+}
+
+fn main() {
+    let config = Config::from_args();
+    if config.hasher_impl_check {
+        println!("hasher\tstate_size_bytes\talignment_bytes\tfits_in_cache_line");
+        print_layout::<siphasher::sip::SipHasher13>("sip13", &config);
+        print_layout::<siphasher::sip::SipHasher24>("sip24", &config);
+        print_layout::<ahash::AHasher>(AHASH_NAME, &config);
+        print_layout::<seahash::SeaHasher>("seahash", &config);
+        print_layout::<hashers::SeaHashFixed>("seahash_fixed", &config);
+        print_layout::<metrohash::MetroHash64>("metro64", &config);
+        print_layout::<metrohash::MetroHash128>("metro128", &config);
+        print_layout::<rustc_hash::FxHasher>("fxhash", &config);
+        print_layout::<wyhash::WyHash>("wyhash", &config);
+        print_layout::<wyhash2::WyHash>("wyhash2", &config);
+        print_layout::<xxhash_rust::xxh64::Xxh64>("xxhash64", &config);
+        print_layout::<fasthash::XXHasher>("xxh64_fasthash", &config);
+        print_layout::<highway::HighwayHasher>("highway", &config);
+        print_layout::<fasthash::T1haHasher>("t1ha", &config);
+        print_layout::<fasthash::t1ha0::Hasher64_64>("t1ha0", &config);
+        print_layout::<fasthash::t1ha1::Hasher64>("t1ha1", &config);
+        print_layout::<hashers::T1ha0_32Le>("t1ha0_32le", &config);
+        print_layout::<T1haNative>("t1ha_native", &config);
+        print_layout::<fnv::FnvHasher>("fnv", &config);
+        print_layout::<fasthash::murmur2::Hasher64_x64>("murmur2", &config);
+        print_layout::<fasthash::murmur3::Hasher128_x64>("murmur3_128_x64", &config);
+        print_layout::<fasthash::murmur3::Hasher32>("murmur3_32", &config);
+        print_layout::<fasthash::murmur3::Hasher128_x86>("murmur3_128_x86", &config);
+        print_layout::<fasthash::CityHasher>("city", &config);
+        print_layout::<fasthash::SpookyHasher>("spooky", &config);
+        print_layout::<fasthash::FarmHasher>("farm", &config);
+        print_layout::<hashers::Xxh32Wrapper>("xxhash32", &config);
+        print_layout::<fasthash::Lookup3Hasher>("lookup3", &config);
+        print_layout::<fasthash::city::Hasher128>("city128", &config);
+        print_layout::<fasthash::SpookyHasherExt>("spooky128", &config);
+        print_layout::<fasthash::FarmHasherExt>("farm128", &config);
+        print_layout::<fasthash::T1haHasherExt>("t1ha128", &config);
+        return;
+    }
+    let out_dir = Path::new("out");
+    if !out_dir.exists() {
+        fs::create_dir(out_dir).unwrap();
+    }
+    let mut writers = Writers::new(out_dir, &config).unwrap();
+    if cfg!(target_arch = "aarch64") {
+        // None of the benchmarked crates provide NEON-specific hashers, but some use NEON when available.
+        eprintln!("AArch64 target, NEON {}\n",
+            if platform::is_arm_neon_available() { "available" } else { "not available" });
+    }
+
+    let rng = rand_xoshiro::Xoshiro256PlusPlus::from_entropy();
+    test_hasher::<siphasher::sip::SipHasher13>("sip13", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<siphasher::sip::SipHasher24>("sip24", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<ahash::AHasher>(AHASH_NAME, 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<seahash::SeaHasher>("seahash", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<hashers::SeaHashFixed>("seahash_fixed", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<metrohash::MetroHash64>("metro64", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<metrohash::MetroHash128>("metro128", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<rustc_hash::FxHasher>("fxhash", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<wyhash::WyHash>("wyhash", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<wyhash2::WyHash>("wyhash2", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<xxhash_rust::xxh64::Xxh64>("xxhash64", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::XXHasher>("xxh64_fasthash", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<highway::HighwayHasher>("highway", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::T1haHasher>("t1ha", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::t1ha0::Hasher64_64>("t1ha0", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::t1ha1::Hasher64>("t1ha1", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<hashers::T1ha0_32Le>("t1ha0_32le", 64, rng.clone(), &config, &mut writers).unwrap();
+    if config.hasher_enabled("t1ha_native") {
+        eprintln!("t1ha_native: using {} t1ha0 implementation", env!("T1HA_NATIVE_VARIANT"));
+    }
+    test_hasher::<T1haNative>("t1ha_native", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fnv::FnvHasher>("fnv", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::murmur2::Hasher64_x64>("murmur2", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::murmur3::Hasher128_x64>("murmur3_128_x64", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::murmur3::Hasher32>("murmur3_32", 32, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::murmur3::Hasher128_x86>("murmur3_128_x86", 64, rng.clone(), &config, &mut writers)
+        .unwrap();
+    test_hasher::<fasthash::CityHasher>("city", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::SpookyHasher>("spooky", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::FarmHasher>("farm", 64, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<hashers::Xxh32Wrapper>("xxhash32", 32, rng.clone(), &config, &mut writers).unwrap();
+    test_hasher::<fasthash::Lookup3Hasher>("lookup3", 32, rng.clone(), &config, &mut writers).unwrap();
+
+    test_seedable_hasher::<siphasher::sip::SipHasher13>("sip13", &config, &mut writers).unwrap();
+    test_seedable_hasher::<siphasher::sip::SipHasher24>("sip24", &config, &mut writers).unwrap();
+    test_seedable_hasher::<ahash::AHasher>(AHASH_NAME, &config, &mut writers).unwrap();
+    test_seedable_hasher::<seahash::SeaHasher>("seahash", &config, &mut writers).unwrap();
+    test_seedable_hasher::<metrohash::MetroHash64>("metro64", &config, &mut writers).unwrap();
+    test_seedable_hasher::<metrohash::MetroHash128>("metro128", &config, &mut writers).unwrap();
+    test_seedable_hasher::<wyhash::WyHash>("wyhash", &config, &mut writers).unwrap();
+    test_seedable_hasher::<wyhash2::WyHash>("wyhash2", &config, &mut writers).unwrap();
+    test_seedable_hasher::<xxhash_rust::xxh64::Xxh64>("xxhash64", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fasthash::XXHasher>("xxh64_fasthash", &config, &mut writers).unwrap();
+    test_seedable_hasher::<highway::HighwayHasher>("highway", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fasthash::T1haHasher>("t1ha", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fnv::FnvHasher>("fnv", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fasthash::murmur2::Hasher64_x64>("murmur2", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fasthash::murmur3::Hasher128_x64>("murmur3_128_x64", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fasthash::murmur3::Hasher32>("murmur3_32", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fasthash::murmur3::Hasher128_x86>("murmur3_128_x86", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fasthash::CityHasher>("city", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fasthash::SpookyHasher>("spooky", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fasthash::FarmHasher>("farm", &config, &mut writers).unwrap();
+    test_seedable_hasher::<fasthash::Lookup3Hasher>("lookup3", &config, &mut writers).unwrap();
+
+    test_cloneable_hasher::<siphasher::sip::SipHasher13>("sip13", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<siphasher::sip::SipHasher24>("sip24", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<ahash::AHasher>(AHASH_NAME, &config, &mut writers).unwrap();
+    test_cloneable_hasher::<seahash::SeaHasher>("seahash", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<wyhash::WyHash>("wyhash", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<wyhash2::WyHash>("wyhash2", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<xxhash_rust::xxh64::Xxh64>("xxhash64", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<highway::HighwayHasher>("highway", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::T1haHasher>("t1ha", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::t1ha0::Hasher64_64>("t1ha0", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::t1ha1::Hasher64>("t1ha1", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<hashers::T1ha0_32Le>("t1ha0_32le", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::murmur2::Hasher64_x64>("murmur2", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::murmur3::Hasher128_x64>("murmur3_128_x64", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::murmur3::Hasher32>("murmur3_32", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::murmur3::Hasher128_x86>("murmur3_128_x86", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::CityHasher>("city", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::SpookyHasher>("spooky", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::FarmHasher>("farm", &config, &mut writers).unwrap();
+    test_cloneable_hasher::<fasthash::Lookup3Hasher>("lookup3", &config, &mut writers).unwrap();
+
+    test_hasher128::<metrohash::MetroHash128>("metro128", rng.clone(), &config, &mut writers).unwrap();
+    test_hasher128::<highway::HighwayHasher>("highway", rng.clone(), &config, &mut writers).unwrap();
+    test_hasher128::<fasthash::murmur3::Hasher128_x64>("murmur3_128_x64", rng.clone(), &config, &mut writers).unwrap();
+    test_hasher128::<fasthash::murmur3::Hasher128_x86>("murmur3_128_x86", rng.clone(), &config, &mut writers)
+        .unwrap();
+    test_hasher128::<fasthash::city::Hasher128>("city128", rng.clone(), &config, &mut writers).unwrap();
+    test_hasher128::<fasthash::SpookyHasherExt>("spooky128", rng.clone(), &config, &mut writers).unwrap();
+    test_hasher128::<fasthash::FarmHasherExt>("farm128", rng.clone(), &config, &mut writers).unwrap();
+    test_hasher128::<fasthash::T1haHasherExt>("t1ha128", rng.clone(), &config, &mut writers).unwrap();
+    if let Some(reference) = writers.verify_vectors.as_ref() {
+        reference.finish().unwrap();
+    }
+    // Flush all output files before postprocessing.
+    std::mem::drop(writers);
+
+    if config.bandwidth {
+        report::postprocess_noise(&out_dir.join("bandwidth.csv")).unwrap();
+        report::postprocess_bandwidth(&out_dir.join("bandwidth.csv")).unwrap();
+        report::postprocess_bandwidth_monotonicity(&out_dir.join("bandwidth.csv")).unwrap();
+        // Produced separately by scripts/bench_compile_time.sh.
+        let compile_times_path = out_dir.join("compile_times.csv");
+        if compile_times_path.exists() {
+            report::postprocess_compile_times(&out_dir.join("summary.csv"), &compile_times_path).unwrap();
+        }
+        if let Some(baseline) = &config.baseline {
+            let mut writer = io::BufWriter::new(fs::File::create(out_dir.join("regression_per_size.csv")).unwrap());
+            report::compare_per_size(&out_dir.join("bandwidth.csv"), baseline, &mut writer).unwrap();
+        }
+    }
+    if config.collisions {
+        report::postprocess_collisions_theory(&out_dir.join("collisions.csv")).unwrap();
+    }
+    if config.bandwidth && config.collisions && config.randomness {
+        let weights = &config.weights;
+        report::postprocess_quality_scores(&out_dir.join("bandwidth.csv"), &out_dir.join("collisions.csv"),
+            &out_dir.join("randomness.csv"), (weights.speed, weights.collision, weights.randomness)).unwrap();
+    }
 }
@@ -0,0 +1,141 @@
+/// Returns true if the program runs on AArch64 CPU with NEON (Advanced SIMD) support.
+pub fn is_arm_neon_available() -> bool {
+    #[cfg(target_arch = "aarch64")]
+    {
+        std::arch::is_aarch64_feature_detected!("neon")
+    }
+    #[cfg(not(target_arch = "aarch64"))]
+    {
+        false
+    }
+}
+
+/// Returns value of the `key` field (in kB) in `/proc/self/status`, converted to bytes.
+#[cfg(target_os = "linux")]
+fn proc_status_bytes(key: &str) -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.strip_prefix(key).is_some_and(|rest| rest.starts_with(':')))?;
+    // Line format: "VmRSS:    1234 kB".
+    let kb: usize = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+/// Returns current resident set size of the process, in bytes:
+/// `VmRSS` in `/proc/self/status` on Linux, `resident_size` from `task_info` on macOS.
+/// On other platforms, returns None.
+pub fn current_rss_bytes() -> Option<usize> {
+    #[cfg(target_os = "linux")]
+    {
+        proc_status_bytes("VmRSS")
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let mut info: libc::mach_task_basic_info = unsafe { std::mem::zeroed() };
+        let mut count = libc::MACH_TASK_BASIC_INFO_COUNT;
+        // `mach_task_self` is deprecated in favor of the mach2 crate, which is not needed otherwise.
+        #[allow(deprecated)]
+        let task = unsafe { libc::mach_task_self() };
+        let status = unsafe {
+            libc::task_info(task, libc::MACH_TASK_BASIC_INFO, (&mut info as *mut libc::mach_task_basic_info).cast(),
+                &mut count)
+        };
+        if status != libc::KERN_SUCCESS {
+            return None;
+        }
+        Some(info.resident_size as usize)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        None
+    }
+}
+
+/// Resets peak resident set size to the current value, so that `peak_rss_bytes` covers only what follows.
+/// Only possible on Linux (by writing `5` to `/proc/self/clear_refs`), returns false otherwise.
+pub fn reset_peak_rss() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::write("/proc/self/clear_refs", "5").is_ok()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        false
+    }
+}
+
+/// Returns peak resident set size of the process since start or since the last `reset_peak_rss`, in bytes:
+/// `VmHWM` in `/proc/self/status` on Linux, `ru_maxrss` from `getrusage` on macOS.
+/// On other platforms, returns None.
+pub fn peak_rss_bytes() -> Option<usize> {
+    #[cfg(target_os = "linux")]
+    {
+        proc_status_bytes("VmHWM")
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+        if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+            return None;
+        }
+        // On macOS, ru_maxrss is in bytes.
+        Some(usage.ru_maxrss as usize)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        None
+    }
+}
+
+/// Returns short microarchitecture name for known x86_64 CPUs (based on `vendor_id`, `cpu family` and `model`),
+/// or the model name for other CPUs.
+#[cfg(target_os = "linux")]
+fn microarch_from_cpuinfo(cpuinfo: &str) -> Option<String> {
+    let field = |name: &str| cpuinfo.lines()
+        .find(|line| line.split(':').next().is_some_and(|key| key.trim() == name))
+        .and_then(|line| line.split_once(':')).map(|(_, value)| value.trim());
+    let family: Option<u32> = field("cpu family").and_then(|value| value.parse().ok());
+    let model: Option<u32> = field("model").and_then(|value| value.parse().ok());
+    let known = match (field("vendor_id"), family, model) {
+        (Some("GenuineIntel"), Some(6), Some(model)) => match model {
+            0x3C | 0x3F | 0x45 | 0x46 => Some("Haswell"),
+            0x3D | 0x47 | 0x4F | 0x56 => Some("Broadwell"),
+            0x4E | 0x5E | 0x55 | 0x8E | 0x9E | 0xA5 | 0xA6 => Some("Skylake"),
+            0x6A | 0x6C | 0x7D | 0x7E => Some("Ice Lake"),
+            0x8C | 0x8D => Some("Tiger Lake"),
+            0x97 | 0x9A => Some("Alder Lake"),
+            0xB7 | 0xBA | 0xBF => Some("Raptor Lake"),
+            0x8F => Some("Sapphire Rapids"),
+            0xCF => Some("Emerald Rapids"),
+            _ => None,
+        },
+        (Some("AuthenticAMD"), Some(0x17), Some(model)) => Some(if model < 0x30 { "Zen" } else { "Zen2" }),
+        (Some("AuthenticAMD"), Some(0x19), Some(model)) => match model {
+            0x10..=0x1F | 0x60..=0x7F | 0xA0..=0xAF => Some("Zen4"),
+            _ => Some("Zen3"),
+        },
+        (Some("AuthenticAMD"), Some(0x1A), _) => Some("Zen5"),
+        _ => None,
+    };
+    // AArch64 kernels do not report model name, CPU part is used instead.
+    known.or_else(|| field("model name")).or_else(|| field("CPU part")).map(str::to_owned)
+}
+
+/// Returns short name of the CPU microarchitecture (such as `Skylake` or `Zen3`), if it is known,
+/// otherwise CPU model name, or `unknown`.
+/// On Linux, it is parsed from `/proc/cpuinfo`, on macOS, taken from `sysctl` (CPU brand or hardware model).
+pub fn cpu_microarch_name() -> String {
+    static NAME: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+    NAME.get_or_init(|| {
+        #[cfg(target_os = "linux")]
+        let name = std::fs::read_to_string("/proc/cpuinfo").ok().and_then(|cpuinfo| microarch_from_cpuinfo(&cpuinfo));
+        #[cfg(target_os = "macos")]
+        let name = ["machdep.cpu.brand_string", "hw.model"].iter().find_map(|key| {
+            let output = std::process::Command::new("sysctl").args(["-n", key]).output().ok()?;
+            let value = String::from_utf8(output.stdout).ok()?.trim().to_owned();
+            (output.status.success() && !value.is_empty()).then_some(value)
+        });
+        #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+        let name: Option<String> = None;
+        name.unwrap_or_else(|| "unknown".to_owned())
+    }).clone()
+}
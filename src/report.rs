@@ -0,0 +1,353 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    collections::BTreeMap,
+};
+use crate::{platform, stats};
+
+/// Version of the benchmark harness: crate version and short git SHA.
+pub const VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), " (", env!("HASH_BENCH_GIT_SHA"), ")");
+
+/// Writes metadata line (`# hash_bench <VERSION>, CPU: <microarchitecture>`, followed by `, NEON: yes/no`
+/// on AArch64), and then the header.
+pub fn write_header(writer: &mut impl Write, header: &str) -> io::Result<()> {
+    write!(writer, "# hash_bench {}, CPU: {}", VERSION, platform::cpu_microarch_name())?;
+    if cfg!(target_arch = "aarch64") {
+        write!(writer, ", NEON: {}", if platform::is_arm_neon_available() { "yes" } else { "no" })?;
+    }
+    writeln!(writer)?;
+    writeln!(writer, "{}", header)
+}
+
+/// Creates output file, and writes metadata line and the header.
+pub fn create_table(path: &Path, header: &str) -> io::Result<io::BufWriter<fs::File>> {
+    let mut writer = io::BufWriter::new(fs::File::create(path)?);
+    write_header(&mut writer, header)?;
+    Ok(writer)
+}
+
+/// Tab-separated table, loaded from one of the output files.
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let mut lines = contents.lines().filter(|line| !line.is_empty() && !line.starts_with('#'));
+        let header = lines.next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("{} is empty", path.display())))?
+            .split('\t').map(str::to_owned).collect::<Vec<_>>();
+        let rows = lines.map(|line| line.split('\t').map(str::to_owned).collect::<Vec<_>>()).collect::<Vec<_>>();
+        if let Some(row) = rows.iter().find(|row| row.len() != header.len()) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData,
+                format!("{}: row {:?} does not match the header", path.display(), row)));
+        }
+        Ok(Self { header, rows })
+    }
+
+    /// Returns index of the column.
+    pub fn column(&self, name: &str) -> io::Result<usize> {
+        self.header.iter().position(|col| col == name)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("Column {} not found", name)))
+    }
+
+    pub fn rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+}
+
+fn parse<T: std::str::FromStr>(s: &str) -> io::Result<T> {
+    s.parse().map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("Cannot parse value {:?}", s)))
+}
+
+/// Computes coefficient of variation (SD / mean) for each hasher and input size,
+/// and writes it to `noise.csv` next to the bandwidth file.
+/// Repeated rows for the same hasher and size are pooled together.
+pub fn postprocess_noise(bandwidth_path: &Path) -> io::Result<()> {
+    let table = Table::load(bandwidth_path)?;
+    let hasher_col = table.column("hasher")?;
+    let bytes_col = table.column("bytes")?;
+    let mean_col = table.column("bandwidth_mean")?;
+    let sd_col = table.column("bandwidth_sd")?;
+
+    // Sums of means and variances, as well as the number of rows, for each hasher and size.
+    let mut groups: BTreeMap<(&str, usize), (f64, f64, usize)> = BTreeMap::new();
+    let mut order = Vec::new();
+    for row in table.rows() {
+        let key = (&row[hasher_col] as &str, parse::<usize>(&row[bytes_col])?);
+        let mean: f64 = parse(&row[mean_col])?;
+        let sd: f64 = parse(&row[sd_col])?;
+        let entry = groups.entry(key).or_insert_with(|| {
+            order.push(key);
+            (0.0, 0.0, 0)
+        });
+        entry.0 += mean;
+        entry.1 += sd * sd;
+        entry.2 += 1;
+    }
+
+    let mut writer = create_table(&bandwidth_path.with_file_name("noise.csv"), "hasher\tbytes\tcv")?;
+    for key in order {
+        let (sum_mean, sum_var, n) = groups[&key];
+        let cv = (sum_var / n as f64).sqrt() / (sum_mean / n as f64);
+        writeln!(writer, "{}\t{}\t{:.10}", key.0, key.1, cv)?;
+    }
+    Ok(())
+}
+
+/// Summarizes bandwidth for each hasher across all input sizes,
+/// and writes it to `summary.csv` next to the bandwidth file.
+pub fn postprocess_bandwidth(bandwidth_path: &Path) -> io::Result<()> {
+    let table = Table::load(bandwidth_path)?;
+    let hasher_col = table.column("hasher")?;
+    let mean_col = table.column("bandwidth_mean")?;
+
+    let mut hashers: Vec<(&str, Vec<f64>)> = Vec::new();
+    for row in table.rows() {
+        let hasher = &row[hasher_col] as &str;
+        let bandwidth = parse(&row[mean_col])?;
+        match hashers.iter_mut().find(|(name, _)| *name == hasher) {
+            Some((_, values)) => values.push(bandwidth),
+            None => hashers.push((hasher, vec![bandwidth])),
+        }
+    }
+
+    let mut writer = create_table(&bandwidth_path.with_file_name("summary.csv"), "hasher\tgeometric_mean_bw_mb_s")?;
+    for (hasher, values) in hashers {
+        writeln!(writer, "{}\t{:.10}", hasher, stats::geometric_mean(&values))?;
+    }
+    Ok(())
+}
+
+/// Compares observed number of collisions with the number, expected for a uniform hash function
+/// (birthday bound: n * (n - 1) / 2 / 2^bits), and writes it to `collision_vs_theory.csv`.
+pub fn postprocess_collisions_theory(collisions_path: &Path) -> io::Result<()> {
+    let table = Table::load(collisions_path)?;
+    let hasher_col = table.column("hasher")?;
+    let bits_col = table.column("bits")?;
+    let bytes_col = table.column("bytes")?;
+    let collisions_col = table.column("collisions")?;
+    let count_col = table.column("count")?;
+
+    let mut writer = create_table(&collisions_path.with_file_name("collision_vs_theory.csv"),
+        "hasher\tlength\tobserved\texpected\tratio")?;
+    for row in table.rows() {
+        let bits: i32 = parse(&row[bits_col])?;
+        let observed: u64 = parse(&row[collisions_col])?;
+        let n: f64 = parse(&row[count_col])?;
+        let expected = 0.5 * n * (n - 1.0) / 2.0_f64.powi(bits);
+        writeln!(writer, "{}\t{}\t{}\t{:.6e}\t{:.6e}", row[hasher_col], row[bytes_col], observed, expected,
+            observed as f64 / expected)?;
+    }
+    Ok(())
+}
+
+/// Crates, measured by `scripts/bench_compile_time.sh`, and the hashers they provide.
+const CRATE_HASHERS: &[(&str, &[&str])] = &[
+    ("siphasher", &["sip13", "sip24"]),
+    ("ahash", &["ahash", "ahash_ctrng"]),
+    ("seahash", &["seahash", "seahash_fixed"]),
+    ("metrohash", &["metro64", "metro128"]),
+    ("rustc-hash", &["fxhash"]),
+    ("wyhash", &["wyhash"]),
+    ("wyhash2", &["wyhash2"]),
+    ("xxhash-rust", &["xxhash64", "xxhash32"]),
+    ("highway", &["highway"]),
+    ("fasthash", &["t1ha", "t1ha0", "t1ha1", "t1ha0_32le", "t1ha_native", "murmur2", "murmur3_32", "murmur3_128_x86",
+        "murmur3_128_x64", "city", "spooky", "farm", "lookup3", "xxh64_fasthash"]),
+    ("fnv", &["fnv"]),
+];
+
+/// Joins bandwidth summary with compile times of the corresponding crates,
+/// and writes it to `summary_compile_time.csv` next to the summary file.
+/// Hashers without known compile time are written with `NA`.
+pub fn postprocess_compile_times(summary_path: &Path, compile_times_path: &Path) -> io::Result<()> {
+    let summary = Table::load(summary_path)?;
+    let hasher_col = summary.column("hasher")?;
+    let bandwidth_col = summary.column("geometric_mean_bw_mb_s")?;
+    let compile_times = Table::load(compile_times_path)?;
+    let crate_col = compile_times.column("crate")?;
+    let time_col = compile_times.column("compile_time_s")?;
+
+    let mut writer = create_table(&summary_path.with_file_name("summary_compile_time.csv"),
+        "hasher\tcrate\tgeometric_mean_bw_mb_s\tcompile_time_s")?;
+    for row in summary.rows() {
+        let hasher = &row[hasher_col] as &str;
+        let crate_name = CRATE_HASHERS.iter().find(|(_, hashers)| hashers.contains(&hasher)).map(|(name, _)| *name);
+        let time = crate_name.and_then(|name| compile_times.rows().iter().find(|time_row| time_row[crate_col] == name))
+            .map(|time_row| parse::<f64>(&time_row[time_col])).transpose()?;
+        match (crate_name, time) {
+            (Some(name), Some(time)) => writeln!(writer, "{}\t{}\t{}\t{:.3}", hasher, name, row[bandwidth_col], time)?,
+            _ => writeln!(writer, "{}\t{}\t{}\tNA", hasher, crate_name.unwrap_or("NA"), row[bandwidth_col])?,
+        }
+    }
+    Ok(())
+}
+
+/// Finds pairs of input sizes for the same hasher, where bandwidth on the larger input is more than 10% lower
+/// than on the smaller input, and writes them to `monotonicity_warnings.csv` next to the bandwidth file.
+/// Repeated rows for the same hasher and size are averaged.
+pub fn postprocess_bandwidth_monotonicity(bandwidth_path: &Path) -> io::Result<()> {
+    const TOLERANCE: f64 = 0.9;
+    let table = Table::load(bandwidth_path)?;
+    let hasher_col = table.column("hasher")?;
+    let bytes_col = table.column("bytes")?;
+    let mean_col = table.column("bandwidth_mean")?;
+
+    // Sum of bandwidth and number of rows for each hasher and size.
+    let mut groups: BTreeMap<(&str, usize), (f64, usize)> = BTreeMap::new();
+    let mut order = Vec::new();
+    for row in table.rows() {
+        let hasher = &row[hasher_col] as &str;
+        let bandwidth: f64 = parse(&row[mean_col])?;
+        let entry = groups.entry((hasher, parse(&row[bytes_col])?)).or_insert((0.0, 0));
+        entry.0 += bandwidth;
+        entry.1 += 1;
+        if !order.contains(&hasher) {
+            order.push(hasher);
+        }
+    }
+
+    let mut writer = create_table(&bandwidth_path.with_file_name("monotonicity_warnings.csv"),
+        "hasher\tbytes_small\tbw_small\tbytes_large\tbw_large\tratio")?;
+    let mut warnings = 0;
+    for hasher in order {
+        let means: Vec<(usize, f64)> = groups.range((hasher, 0)..=(hasher, usize::MAX))
+            .map(|(&(_, bytes), &(sum, n))| (bytes, sum / n as f64)).collect();
+        for (i, &(bytes_small, bw_small)) in means.iter().enumerate() {
+            for &(bytes_large, bw_large) in &means[i + 1..] {
+                if bw_large < bw_small * TOLERANCE {
+                    writeln!(writer, "{}\t{}\t{:.3}\t{}\t{:.3}\t{:.6}", hasher, bytes_small, bw_small, bytes_large,
+                        bw_large, bw_large / bw_small)?;
+                    warnings += 1;
+                }
+            }
+        }
+    }
+    if warnings > 0 {
+        eprintln!("Warning: bandwidth decreases with input size in {} cases, see monotonicity_warnings.csv",
+            warnings);
+    }
+    Ok(())
+}
+
+/// Mean bandwidth for each hasher and input size, repeated rows are averaged.
+fn mean_bandwidth_by_size(table: &Table) -> io::Result<BTreeMap<(&str, usize), f64>> {
+    let hasher_col = table.column("hasher")?;
+    let bytes_col = table.column("bytes")?;
+    let mean_col = table.column("bandwidth_mean")?;
+    let mut groups: BTreeMap<(&str, usize), (f64, usize)> = BTreeMap::new();
+    for row in table.rows() {
+        let entry = groups.entry((&row[hasher_col], parse(&row[bytes_col])?)).or_insert((0.0, 0));
+        entry.0 += parse::<f64>(&row[mean_col])?;
+        entry.1 += 1;
+    }
+    Ok(groups.into_iter().map(|(key, (sum, n))| (key, sum / n as f64)).collect())
+}
+
+/// Compares bandwidth of the current run with a baseline run for each hasher and input size,
+/// so that regressions on specific input sizes are not hidden by the aggregate summary.
+/// Sizes with bandwidth more than 10% lower than in the baseline are marked as regressions.
+/// Hashers and sizes, present in only one of the files, are skipped.
+pub fn compare_per_size(current: &Path, baseline: &Path, writer: &mut impl Write) -> io::Result<()> {
+    const TOLERANCE: f64 = 0.9;
+    let current_table = Table::load(current)?;
+    let baseline_table = Table::load(baseline)?;
+    let current_means = mean_bandwidth_by_size(&current_table)?;
+    let baseline_means = mean_bandwidth_by_size(&baseline_table)?;
+
+    write_header(writer, "hasher\tbytes\tbw_baseline\tbw_current\tratio\tregression")?;
+    let mut regressions = 0;
+    for (&(hasher, bytes), &bw_current) in &current_means {
+        let Some(&bw_baseline) = baseline_means.get(&(hasher, bytes)) else { continue };
+        let ratio = bw_current / bw_baseline;
+        let regression = ratio < TOLERANCE;
+        writeln!(writer, "{}\t{}\t{:.3}\t{:.3}\t{:.6}\t{}", hasher, bytes, bw_baseline, bw_current, ratio,
+            regression)?;
+        regressions += usize::from(regression);
+    }
+    if regressions > 0 {
+        eprintln!("Warning: bandwidth decreased compared to the baseline in {} cases", regressions);
+    }
+    Ok(())
+}
+
+/// Combines bandwidth, collision rate and randomness into a single score between 0 and 1 (higher is better):
+/// - speed: `(log10(bandwidth) - 1) / 4`, clamped to [0, 1] (10 Mb/s corresponds to 0, 100 Gb/s to 1),
+/// - collisions: `1 / max(1, collision_rate)`, where the collision rate is the number of observed collisions
+///   divided by the number, expected for a uniform hash function,
+/// - randomness: `randomness01` from `randomness.csv`.
+///
+/// Components are averaged with `(speed, collision, randomness)` weights, normalized by their sum.
+pub fn compute_quality_score(
+    bandwidth_mb_s: f64,
+    collision_rate: f64,
+    randomness01: f64,
+    weights: (f64, f64, f64),
+) -> f64 {
+    let speed = ((bandwidth_mb_s.log10() - 1.0) / 4.0).clamp(0.0, 1.0);
+    let collisions = 1.0 / collision_rate.max(1.0);
+    (weights.0 * speed + weights.1 * collisions + weights.2 * randomness01) / (weights.0 + weights.1 + weights.2)
+}
+
+/// Computes quality score (see `compute_quality_score`) for each hasher, present in all three files,
+/// using the geometric mean of bandwidth, total collision rate across all input lengths, and mean randomness.
+/// Writes hashers ranked by the score to `quality_scores.csv` next to the bandwidth file.
+pub fn postprocess_quality_scores(
+    bandwidth_path: &Path,
+    collisions_path: &Path,
+    randomness_path: &Path,
+    weights: (f64, f64, f64),
+) -> io::Result<()> {
+    let bandwidth_table = Table::load(bandwidth_path)?;
+    let hasher_col = bandwidth_table.column("hasher")?;
+    let mean_col = bandwidth_table.column("bandwidth_mean")?;
+    let mut bandwidths: BTreeMap<&str, Vec<f64>> = BTreeMap::new();
+    for row in bandwidth_table.rows() {
+        bandwidths.entry(&row[hasher_col]).or_default().push(parse(&row[mean_col])?);
+    }
+
+    // Sums of observed and expected collisions.
+    let collisions_table = Table::load(collisions_path)?;
+    let hasher_col = collisions_table.column("hasher")?;
+    let bits_col = collisions_table.column("bits")?;
+    let collisions_col = collisions_table.column("collisions")?;
+    let count_col = collisions_table.column("count")?;
+    let mut collisions: BTreeMap<&str, (f64, f64)> = BTreeMap::new();
+    for row in collisions_table.rows() {
+        let n: f64 = parse(&row[count_col])?;
+        let entry = collisions.entry(&row[hasher_col]).or_default();
+        entry.0 += parse::<f64>(&row[collisions_col])?;
+        entry.1 += 0.5 * n * (n - 1.0) / 2.0_f64.powi(parse(&row[bits_col])?);
+    }
+
+    let randomness_table = Table::load(randomness_path)?;
+    let hasher_col = randomness_table.column("hasher")?;
+    let randomness_col = randomness_table.column("randomness")?;
+    let mut randomness: BTreeMap<&str, Vec<f64>> = BTreeMap::new();
+    for row in randomness_table.rows() {
+        randomness.entry(&row[hasher_col]).or_default().push(parse(&row[randomness_col])?);
+    }
+
+    let mut scores = Vec::new();
+    for (hasher, values) in &bandwidths {
+        let (Some(&(observed, expected)), Some(randomness_values)) = (collisions.get(hasher), randomness.get(hasher))
+            else { continue };
+        let bandwidth = stats::geometric_mean(values);
+        let collision_rate = observed / expected;
+        let randomness01 = stats::mean_variance(randomness_values).0;
+        let score = compute_quality_score(bandwidth, collision_rate, randomness01, weights);
+        scores.push((*hasher, bandwidth, collision_rate, randomness01, score));
+    }
+    scores.sort_by(|a, b| b.4.total_cmp(&a.4));
+
+    let mut writer = create_table(&bandwidth_path.with_file_name("quality_scores.csv"),
+        "hasher\tbandwidth\tcollision_rate\trandomness\tscore")?;
+    for (hasher, bandwidth, collision_rate, randomness01, score) in scores {
+        writeln!(writer, "{}\t{:.3}\t{:.6e}\t{:.10}\t{:.6}", hasher, bandwidth, collision_rate, randomness01, score)?;
+    }
+    Ok(())
+}
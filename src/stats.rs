@@ -0,0 +1,306 @@
+/// Returns mean and variance together.
+pub fn mean_variance(a: &[f64]) -> (f64, f64) {
+    let n = a.len();
+    assert!(n > 1);
+    let mean = a.iter().sum::<f64>() / n as f64;
+    let var = a.iter().fold(0.0, |acc, x| {
+            let diff = x - mean;
+            acc + diff * diff
+        }) / (n - 1) as f64;
+    (mean, var)
+}
+
+/// Pearson correlation coefficient between two samples of the same length.
+pub fn pearson(a: &[f64], b: &[f64]) -> f64 {
+    assert_eq!(a.len(), b.len());
+    let (mean_a, var_a) = mean_variance(a);
+    let (mean_b, var_b) = mean_variance(b);
+    let cov = a.iter().zip(b).fold(0.0, |acc, (x, y)| acc + (x - mean_a) * (y - mean_b))
+        / (a.len() - 1) as f64;
+    cov / (var_a * var_b).sqrt()
+}
+
+/// Geometric mean of positive values.
+pub fn geometric_mean(a: &[f64]) -> f64 {
+    assert!(!a.is_empty());
+    (a.iter().map(|x| x.ln()).sum::<f64>() / a.len() as f64).exp()
+}
+
+/// Median of the values.
+pub fn median(a: &[f64]) -> f64 {
+    assert!(!a.is_empty());
+    let mut sorted = a.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    let n = sorted.len();
+    if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        0.5 * (sorted[n / 2 - 1] + sorted[n / 2])
+    }
+}
+
+/// Median absolute deviation: median of |x_i - median|.
+pub fn median_absolute_deviation(a: &[f64]) -> f64 {
+    let med = median(a);
+    let deviations: Vec<f64> = a.iter().map(|x| (x - med).abs()).collect();
+    median(&deviations)
+}
+
+/// Kolmogorov-Smirnov statistic: maximal deviation of the empirical CDF of `outputs`
+/// from the CDF of the uniform distribution over all u64 values.
+pub fn ks_statistic_vs_uniform(outputs: &[u64]) -> f64 {
+    assert!(!outputs.is_empty());
+    let mut sorted = outputs.to_vec();
+    sorted.sort_unstable();
+    let n = sorted.len() as f64;
+    sorted.iter().enumerate().fold(0.0, |acc: f64, (i, &x)| {
+        let cdf = x as f64 / 2.0_f64.powi(64);
+        acc.max((i + 1) as f64 / n - cdf).max(cdf - i as f64 / n)
+    })
+}
+
+/// Pearson's chi-squared statistic of the observed bin counts against the uniform distribution over all bins.
+pub fn chi_squared_uniform(observed: &[u64]) -> f64 {
+    assert!(!observed.is_empty());
+    let expected = observed.iter().sum::<u64>() as f64 / observed.len() as f64;
+    observed.iter().fold(0.0, |acc, &x| {
+        let diff = x as f64 - expected;
+        acc + diff * diff
+    }) / expected
+}
+
+/// Quantile of the values (`q` between 0 and 1), using the nearest rank method.
+pub fn quantile(a: &[f64], q: f64) -> f64 {
+    assert!((0.0..=1.0).contains(&q));
+    let mut sorted = a.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    percentile(&sorted, 100.0 * q)
+}
+
+/// Percentile of already sorted values (`p` between 0 and 100), using the nearest rank method.
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    assert!(!sorted.is_empty() && (0.0..=100.0).contains(&p));
+    let rank = (0.01 * p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.saturating_sub(1)]
+}
+
+/// Truncated mean: mean of the values after discarding `trim_fraction` of the lowest and of the highest values.
+pub fn trim_mean(a: &[f64], trim_fraction: f64) -> f64 {
+    assert!(!a.is_empty() && (0.0..0.5).contains(&trim_fraction));
+    let mut sorted = a.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+    let k = (trim_fraction * sorted.len() as f64).floor() as usize;
+    let trimmed = &sorted[k..sorted.len() - k];
+    trimmed.iter().sum::<f64>() / trimmed.len() as f64
+}
+
+/// Natural logarithm of the gamma function (Lanczos approximation), for positive `x`.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFS: [f64; 6] = [76.18009172947146, -86.50532032941677, 24.01409824083091, -1.231739572450155,
+        0.1208650973866179e-2, -0.5395239384953e-5];
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let mut y = x;
+    let ser = COEFS.iter().fold(1.000000000190015, |acc, c| {
+        y += 1.0;
+        acc + c / y
+    });
+    -tmp + (2.5066282746310005 * ser / x).ln()
+}
+
+/// Continued fraction for the incomplete beta function (modified Lentz's method).
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITER: usize = 10000;
+    const EPS: f64 = 1e-15;
+    const FPMIN: f64 = 1e-300;
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+    if d.abs() < FPMIN {
+        d = FPMIN;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+    for m in 1..=MAX_ITER {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        for aa in [m * (b - m) * x / ((qam + m2) * (a + m2)), -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2))] {
+            d = 1.0 + aa * d;
+            if d.abs() < FPMIN {
+                d = FPMIN;
+            }
+            c = 1.0 + aa / c;
+            if c.abs() < FPMIN {
+                c = FPMIN;
+            }
+            d = 1.0 / d;
+            h *= d * c;
+        }
+        if (d * c - 1.0).abs() < EPS {
+            break;
+        }
+    }
+    h
+}
+
+/// Regularized incomplete beta function I_x(a, b).
+fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    } else if x >= 1.0 {
+        return 1.0;
+    }
+    let front = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Quantile of the beta distribution, found by bisection.
+fn beta_quantile(p: f64, a: f64, b: f64) -> f64 {
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..200 {
+        let mid = 0.5 * (low + high);
+        if incomplete_beta(a, b, mid) < p {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    0.5 * (low + high)
+}
+
+/// Clopper-Pearson exact binomial confidence interval with confidence level `1 - alpha`
+/// for the success probability, given the number of successes and trials.
+pub fn clopper_pearson(successes: u64, trials: u64, alpha: f64) -> (f64, f64) {
+    assert!(successes <= trials && trials > 0);
+    let k = successes as f64;
+    let n = trials as f64;
+    let low = if successes == 0 { 0.0 } else { beta_quantile(0.5 * alpha, k, n - k + 1.0) };
+    let high = if successes == trials { 1.0 } else { beta_quantile(1.0 - 0.5 * alpha, k + 1.0, n - k) };
+    (low, high)
+}
+
+/// Complementary error function (Chebyshev approximation, relative error below 1.2e-7).
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.26551223 + t * (1.00002368 + t * (0.37409196 + t * (0.09678418 + t * (-0.18628806
+        + t * (0.27886807 + t * (-1.13520398 + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+    let res = t * poly.exp();
+    if x >= 0.0 { res } else { 2.0 - res }
+}
+
+/// Wald-Wolfowitz runs test: compares the number of runs (maximal blocks of equal values) in the binary sequence
+/// with the number, expected for a random sequence with the same number of `true` and `false` values.
+/// Returns Z-statistic and two-sided p-value (normal approximation).
+/// Negative Z means too few runs (clustering), positive Z means too many runs (too frequent alternation).
+pub fn runs_test(bits: &[bool]) -> (f64, f64) {
+    let n = bits.len() as f64;
+    let n1 = bits.iter().filter(|&&b| b).count() as f64;
+    let n2 = n - n1;
+    assert!(n1 > 0.0 && n2 > 0.0, "Runs test requires both values to be present");
+    let runs = 1 + bits.windows(2).filter(|w| w[0] != w[1]).count();
+    let mean = 2.0 * n1 * n2 / n + 1.0;
+    let var = (mean - 1.0) * (mean - 2.0) / (n - 1.0);
+    let z = (runs as f64 - mean) / var.sqrt();
+    (z, erfc(z.abs() / std::f64::consts::SQRT_2))
+}
+
+/// Upper-tail p-value of the chi-squared statistic with `df` degrees of freedom
+/// (Wilson-Hilferty normal approximation, accurate for `df` above ~30).
+pub fn chi_squared_p_value(chi2: f64, df: f64) -> f64 {
+    let s = 2.0 / (9.0 * df);
+    let z = ((chi2 / df).cbrt() - (1.0 - s)) / s.sqrt();
+    0.5 * erfc(z / std::f64::consts::SQRT_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!((actual - expected).abs() < tolerance, "{} is not within {} of {}", actual, tolerance, expected);
+    }
+
+    #[test]
+    fn ln_gamma_known_values() {
+        assert_close(ln_gamma(1.0), 0.0, 1e-10);
+        assert_close(ln_gamma(5.0), 24.0_f64.ln(), 1e-10);
+        assert_close(ln_gamma(0.5), std::f64::consts::PI.sqrt().ln(), 1e-10);
+    }
+
+    #[test]
+    fn incomplete_beta_known_values() {
+        // I_x(1, 1) = x, I_x(a, 1) = x^a, and I_0.5(a, a) = 0.5 by symmetry.
+        assert_close(incomplete_beta(1.0, 1.0, 0.3), 0.3, 1e-10);
+        assert_close(incomplete_beta(3.0, 1.0, 0.6), 0.6_f64.powi(3), 1e-10);
+        assert_close(incomplete_beta(7.5, 7.5, 0.5), 0.5, 1e-10);
+        assert_eq!(incomplete_beta(2.0, 3.0, 0.0), 0.0);
+        assert_eq!(incomplete_beta(2.0, 3.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn beta_quantile_inverts_incomplete_beta() {
+        for p in [0.025, 0.5, 0.975] {
+            assert_close(incomplete_beta(5.0, 96.0, beta_quantile(p, 5.0, 96.0)), p, 1e-10);
+        }
+    }
+
+    #[test]
+    fn clopper_pearson_known_values() {
+        let (low, high) = clopper_pearson(5, 100, 0.05);
+        assert_close(low, 0.01643, 1e-5);
+        assert_close(high, 0.11284, 1e-5);
+        // With 0 or n successes, the interval is one-sided: the bound is 1 - (alpha / 2)^(1 / n) or its mirror.
+        let bound = 0.025_f64.powf(0.01);
+        let (low, high) = clopper_pearson(0, 100, 0.05);
+        assert_eq!(low, 0.0);
+        assert_close(high, 1.0 - bound, 1e-8);
+        let (low, high) = clopper_pearson(100, 100, 0.05);
+        assert_close(low, bound, 1e-8);
+        assert_eq!(high, 1.0);
+    }
+
+    #[test]
+    fn erfc_known_values() {
+        assert_close(erfc(0.0), 1.0, 1e-7);
+        assert_close(erfc(1.0), 0.1572992, 1e-7);
+        assert_close(erfc(-1.0), 2.0 - 0.1572992, 1e-7);
+    }
+
+    #[test]
+    fn runs_test_alternating() {
+        // 20 alternating values: 10 of each, 20 runs. Expected runs 11, variance 90 / 19.
+        let bits: Vec<bool> = (0..20).map(|i| i % 2 == 0).collect();
+        let (z, p) = runs_test(&bits);
+        assert_close(z, 4.135215, 1e-6);
+        assert_close(p, 3.5462e-5, 1e-8);
+    }
+
+    #[test]
+    #[should_panic(expected = "Runs test requires both values to be present")]
+    fn runs_test_constant() {
+        runs_test(&[true; 20]);
+    }
+
+    #[test]
+    fn chi_squared_p_value_known_values() {
+        // Median of the chi-squared distribution approaches df for large df.
+        assert_close(chi_squared_p_value(10000.0, 10000.0), 0.5, 0.01);
+        // Critical value at the 5% level for 100 degrees of freedom.
+        assert_close(chi_squared_p_value(124.342, 100.0), 0.05, 1e-3);
+    }
+
+    #[test]
+    fn ks_statistic_known_values() {
+        assert_eq!(ks_statistic_vs_uniform(&[1 << 63; 10]), 0.5);
+        // Centers of 16 equal intervals deviate from the uniform CDF by half an interval.
+        let centers: Vec<u64> = (0..16).map(|i| (2 * i + 1) << 59).collect();
+        assert_close(ks_statistic_vs_uniform(&centers), 1.0 / 32.0, 1e-12);
+    }
+}
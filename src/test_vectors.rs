@@ -0,0 +1,157 @@
+//! Known hash values of default-constructed hashers, as used by `calc`.
+//! The source of the values is given next to each table (crate versions match Cargo.lock).
+//! Keyed hashers (SipHash, HighwayHash) are checked with the keys of the upstream vectors.
+//! Checked by `tests/test_vectors.rs` to detect regressions after dependency updates.
+//!
+//! Not covered:
+//! - ahash: keys are random (generated at runtime or at compile time),
+//! - fxhash: rustc-hash 1.1.0 has no test vectors,
+//! - wyhash2: wyhash2 0.2.1 has no tests,
+//! - seahash_fixed: custom seeds have no upstream vectors; instead, it is compared with
+//!   `seahash::reference::hash_seeded` with the same seeds,
+//! - t1ha_native: implementation depends on the target.
+
+/// Input, followed by the expected hash value.
+pub type Vector = (&'static [u8], u64);
+
+/// Test key from the metrohash test suite (metrohash-1.0.7 src/tests.rs, `TEST_KEY_63`).
+const METRO_KEY: &[u8] = b"012345678901234567890123456789012345678901234567890123456789012";
+
+/// FNV-1a 64-bit reference values. Source: fnv-1.0.7 lib.rs, `basic_tests`.
+pub const FNV: &[Vector] = &[
+    (b"", 0xcbf29ce484222325),
+    (b"a", 0xaf63dc4c8601ec8c),
+    (b"foobar", 0x85944171f73967e8),
+    (b"\0", 0xaf63bd4c8601b7df),
+];
+
+/// Source: wyhash-0.5.0 tests/integration.rs, `default_constructed`.
+pub const WYHASH: &[Vector] = &[
+    (&[0], 0x8c73a8ab46596ae4),
+];
+
+/// Source: seahash-4.1.0 src/reference.rs, `shakespear`.
+pub const SEAHASH: &[Vector] = &[
+    (b"to be or not to be", 1988685042348123509),
+];
+
+/// Source: metrohash-1.0.7 src/tests.rs (metrohash64 with seed 0, bytes read as little-endian u64).
+pub const METRO64: &[Vector] = &[
+    (METRO_KEY, 0xad4b7006ae3d756b),
+];
+
+/// Lower 64 bits of the 128-bit hash.
+/// Source: metrohash-1.0.7 src/tests.rs (metrohash128 with seed 0, first 8 bytes read as little-endian u64).
+pub const METRO128: &[Vector] = &[
+    (METRO_KEY, 0x9b9feda4bfe27cc7),
+];
+
+/// Sources: empty input from xxHash cli/xsum_sanity_check.c (seed 0), "hello" from fasthash-0.4.0 src/xx.rs.
+pub const XXHASH64: &[Vector] = &[
+    (b"", 0xef46db3751d8e999),
+    (b"hello", 2794345569481354659),
+];
+
+/// Sources: empty input from xxHash cli/xsum_sanity_check.c (seed 0), others from fasthash-0.4.0 src/xx.rs.
+pub const XXHASH32: &[Vector] = &[
+    (b"", 0x02cc5d05),
+    (b"hello", 4211111929),
+    (b"helloworld", 593682946),
+];
+
+/// Source: fasthash-0.4.0 src/t1ha.rs, `t1ha0::Hash64` (same values as t1ha2 for 64-bit targets).
+pub const T1HA0: &[Vector] = &[
+    (b"hello", 3053206065578472372),
+    (b"helloworld", 15302361616348747620),
+];
+
+/// 64-byte pattern of the t1ha reference tests. Source: fasthash-sys-0.3.2 src/t1ha/tests/test.c, `pattern`.
+pub const T1HA_PATTERN: &[u8] = &[
+    0, 1, 2, 3, 4, 5, 6, 7, 0xff, 0x7f, 0x3f, 0x1f, 0xf, 8, 16, 32, 64, 0x80, 0xfe,
+    0xfc, 0xf8, 0xf0, 0xe0, 0xc0, 0xfd, 0xfb, 0xf7, 0xef, 0xdf, 0xbf, 0x55, 0xaa,
+    11, 17, 19, 23, 29, 37, 42, 43, b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i',
+    b'j', b'k', b'l', b'm', b'n', b'o', b'p', b'q', b'r', b's', b't', b'u', b'v', b'w', b'x',
+];
+
+/// Source: fasthash-sys-0.3.2 src/t1ha/tests/test.c, `refval_32le` (`empty-zero` and `bin64-zero`, seed 0).
+pub const T1HA0_32LE: &[Vector] = &[
+    (b"", 0),
+    (T1HA_PATTERN, 0x3df1354b0dfdc443),
+];
+
+/// Source: fasthash-0.4.0 src/t1ha.rs, `t1ha1::Hash64Le`.
+pub const T1HA1: &[Vector] = &[
+    (b"hello", 12810198970222070563),
+    (b"helloworld", 16997942636322422782),
+];
+
+/// Source: fasthash-0.4.0 src/murmur2.rs, `Hash64_x64`.
+pub const MURMUR2: &[Vector] = &[
+    (b"hello", 2191231550387646743),
+    (b"helloworld", 2139823713852166039),
+];
+
+/// Lower 64 bits of the 128-bit hash. Source: fasthash-0.4.0 src/murmur3.rs, `Hash128_x64`.
+pub const MURMUR3: &[Vector] = &[
+    (b"hello", 14688674573012802306),
+    (b"helloworld", 10256632503372987514),
+];
+
+/// Source: fasthash-0.4.0 src/city.rs, `Hash64`.
+pub const CITY: &[Vector] = &[
+    (b"hello", 2578220239953316063),
+    (b"helloworld", 16622738483577116029),
+];
+
+/// Source: fasthash-0.4.0 src/spooky.rs, `Hash64`.
+pub const SPOOKY: &[Vector] = &[
+    (b"hello", 6105954949053820864),
+    (b"helloworld", 18412934266828208920),
+];
+
+/// Source: fasthash-0.4.0 src/farm.rs, `Hash64`.
+pub const FARM: &[Vector] = &[
+    (b"hello", 14403600180753024522),
+    (b"helloworld", 1077737941828767314),
+];
+
+/// Upper 32 bits are zero. Source: fasthash-0.4.0 src/lookup3.rs, `Hash32`.
+pub const LOOKUP3: &[Vector] = &[
+    (b"hello", 885767278),
+    (b"helloworld", 1392336737),
+];
+
+/// Source: fasthash-0.4.0 src/t1ha.rs, `t1ha2::Hasher64`.
+pub const T1HA2: &[Vector] = &[
+    (b"hello", 3053206065578472372),
+    (b"helloworld", 15302361616348747620),
+];
+
+/// Keys of the SipHash reference vectors (bytes 00..0f).
+pub const SIP_KEYS: (u64, u64) = (0x0706050403020100, 0x0f0e0d0c0b0a0908);
+
+/// Keyed with `SIP_KEYS`. Source: siphasher-1.0.4 src/tests.rs, `test_siphash_1_3`.
+pub const SIP13: &[Vector] = &[
+    (b"", 0xabac0158050fc4dc),
+    (&[0], 0xc9f49bf37d57ca93),
+    (&[0, 1], 0x82cb9b024dc7d44d),
+];
+
+/// Keyed with `SIP_KEYS`. Source: siphasher-1.0.4 src/tests.rs, `test_siphash_2_4`
+/// (vectors from the SipHash reference implementation).
+pub const SIP24: &[Vector] = &[
+    (b"", 0x726fdb47dd0e0e31),
+    (&[0], 0x74f839c593dc67fd),
+    (&[0, 1], 0x0d6c8009d9a94f5a),
+];
+
+/// Key of the HighwayHash example.
+pub const HIGHWAY_KEY: [u64; 4] = [1, 2, 3, 4];
+
+/// Keyed with `HIGHWAY_KEY`. Source: highway-1.3.0 src/lib.rs (crate documentation, `finalize64`).
+pub const HIGHWAY: &[Vector] = &[
+    (&[255], 0x7858f24d2d79b2b2),
+];
+
+/// Inputs for the comparison of seahash_fixed with the seahash reference implementation.
+pub const SEAHASH_FIXED_INPUTS: &[&[u8]] = &[b"", b"a", b"to be or not to be", METRO_KEY];
@@ -0,0 +1,111 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    hash::Hasher,
+    io::{self, Write},
+    path::Path,
+};
+use rand::{Rng, SeedableRng};
+
+/// Seed of the fixed input set.
+pub const FIXED_SEED: u64 = 42;
+/// Number of inputs per hasher.
+pub const COUNT: usize = 1000;
+/// Inputs have random lengths between 1 and `MAX_LENGTH`.
+pub const MAX_LENGTH: usize = 128;
+/// AHasher keys are generated at runtime or at compile time, so its hashes differ between runs and builds.
+/// t1ha_native uses different t1ha0 implementations (with different outputs) depending on the target.
+pub const SKIPPED: &[&str] = &["ahash", "ahash_ctrng", "t1ha_native"];
+
+/// Generates the fixed input set. Only platform-independent generation is used (`fill` and `gen_range`),
+/// so that the inputs are the same on all architectures.
+pub fn inputs() -> Vec<Vec<u8>> {
+    let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(FIXED_SEED);
+    (0..COUNT).map(|_| {
+        let mut input = vec![0; rng.gen_range(1..=MAX_LENGTH)];
+        rng.fill(&mut input[..]);
+        input
+    }).collect()
+}
+
+/// Hashes all fixed inputs.
+pub fn hash_all<H: Hasher + Default>(inputs: &[Vec<u8>]) -> Vec<u64> {
+    inputs.iter().map(|input| {
+        let mut hasher = H::default();
+        hasher.write(input);
+        hasher.finish()
+    }).collect()
+}
+
+/// Writes a block of hash values: hasher name length (u8), hasher name (UTF-8),
+/// and `COUNT` hash values as raw little-endian u64 values.
+pub fn export(writer: &mut impl Write, name: &str, hashes: &[u64]) -> io::Result<()> {
+    assert_eq!(hashes.len(), COUNT);
+    writer.write_all(&[u8::try_from(name.len()).expect("Hasher name is too long")])?;
+    writer.write_all(name.as_bytes())?;
+    for hash in hashes {
+        writer.write_all(&hash.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// Reference hash values, exported on another machine, for each hasher.
+/// Hashers are matched by name, so both runs do not need to benchmark the same hashers.
+pub struct Reference {
+    hashers: BTreeMap<String, Vec<u64>>,
+    /// Number of hashers that were compared with the reference.
+    checked: usize,
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+impl Reference {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let bytes = fs::read(path)?;
+        let mut hashers = BTreeMap::new();
+        let mut rest = &bytes[..];
+        while let Some((&name_len, tail)) = rest.split_first() {
+            let block_len = usize::from(name_len) + 8 * COUNT;
+            if tail.len() < block_len {
+                return Err(invalid_data(format!("{}: truncated block", path.display())));
+            }
+            let (name, values) = tail[..block_len].split_at(usize::from(name_len));
+            let name = std::str::from_utf8(name)
+                .map_err(|_| invalid_data(format!("{}: hasher name is not UTF-8", path.display())))?;
+            let hashes = values.chunks_exact(8).map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap())).collect();
+            hashers.insert(name.to_owned(), hashes);
+            rest = &tail[block_len..];
+        }
+        Ok(Self { hashers, checked: 0 })
+    }
+
+    /// Compares hash values of the hasher with the reference. Hashers, absent from the reference, are skipped.
+    pub fn verify(&mut self, name: &str, hashes: &[u64]) -> io::Result<()> {
+        eprintln!("Comparing {} with reference vectors", name);
+        let Some(reference) = self.hashers.get(name) else {
+            eprintln!("    -> not in the reference, skipped");
+            return Ok(());
+        };
+        self.checked += 1;
+        if let Some(i) = reference.iter().zip(hashes).position(|(a, b)| a != b) {
+            return Err(invalid_data(
+                format!("{}: hash of input {} is {:016x}, reference {:016x}", name, i, hashes[i], reference[i])));
+        }
+        eprintln!("    -> all {} hash values match", hashes.len());
+        Ok(())
+    }
+
+    /// Checks that at least one hasher was compared, and reports the number of unchecked reference hashers.
+    pub fn finish(&self) -> io::Result<()> {
+        if self.checked == 0 {
+            return Err(invalid_data("None of the reference hashers were checked".to_owned()));
+        }
+        if self.checked < self.hashers.len() {
+            eprintln!("Warning: {} out of {} reference hashers were not benchmarked in this run",
+                self.hashers.len() - self.checked, self.hashers.len());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+use std::hash::Hasher;
+
+#[allow(dead_code)]
+#[path = "../src/hashers.rs"]
+mod hashers;
+#[path = "../src/test_vectors.rs"]
+mod test_vectors;
+
+use test_vectors::Vector;
+
+/// Same as `calc` in the benchmark.
+fn calc<H: Hasher + Default>(bytes: &[u8]) -> u64 {
+    let mut hasher = H::default();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+fn check<H: Hasher + Default>(vectors: &[Vector]) {
+    check_with(H::default, vectors);
+}
+
+/// Same as `check`, but hashers are constructed with `new` (for keyed vectors).
+fn check_with<H: Hasher>(new: impl Fn() -> H, vectors: &[Vector]) {
+    for &(input, expected) in vectors {
+        let mut hasher = new();
+        hasher.write(input);
+        assert_eq!(hasher.finish(), expected, "Hash mismatch for input {:?}", String::from_utf8_lossy(input));
+    }
+}
+
+#[test]
+fn fnv() {
+    check::<fnv::FnvHasher>(test_vectors::FNV);
+}
+
+#[test]
+fn wyhash() {
+    check::<wyhash::WyHash>(test_vectors::WYHASH);
+}
+
+#[test]
+fn seahash() {
+    check::<seahash::SeaHasher>(test_vectors::SEAHASH);
+}
+
+#[test]
+fn metro64() {
+    check::<metrohash::MetroHash64>(test_vectors::METRO64);
+}
+
+#[test]
+fn metro128() {
+    check::<metrohash::MetroHash128>(test_vectors::METRO128);
+}
+
+#[test]
+fn xxhash64() {
+    check::<xxhash_rust::xxh64::Xxh64>(test_vectors::XXHASH64);
+}
+
+#[test]
+fn xxh64_fasthash() {
+    check::<fasthash::XXHasher>(test_vectors::XXHASH64);
+}
+
+#[test]
+fn xxhash32() {
+    check::<hashers::Xxh32Wrapper>(test_vectors::XXHASH32);
+}
+
+#[test]
+fn t1ha0() {
+    check::<fasthash::t1ha0::Hasher64_64>(test_vectors::T1HA0);
+}
+
+#[test]
+fn t1ha1() {
+    check::<fasthash::t1ha1::Hasher64>(test_vectors::T1HA1);
+}
+
+#[test]
+fn t1ha0_32le() {
+    check::<hashers::T1ha0_32Le>(test_vectors::T1HA0_32LE);
+}
+
+#[test]
+fn murmur2() {
+    check::<fasthash::murmur2::Hasher64_x64>(test_vectors::MURMUR2);
+}
+
+#[test]
+fn murmur3() {
+    check::<fasthash::murmur3::Hasher128_x64>(test_vectors::MURMUR3);
+}
+
+#[test]
+fn city() {
+    check::<fasthash::CityHasher>(test_vectors::CITY);
+}
+
+#[test]
+fn spooky() {
+    check::<fasthash::SpookyHasher>(test_vectors::SPOOKY);
+}
+
+#[test]
+fn farm() {
+    check::<fasthash::FarmHasher>(test_vectors::FARM);
+}
+
+#[test]
+fn lookup3() {
+    check::<fasthash::Lookup3Hasher>(test_vectors::LOOKUP3);
+}
+
+#[test]
+fn t1ha() {
+    check::<fasthash::T1haHasher>(test_vectors::T1HA2);
+}
+
+#[test]
+fn sip13() {
+    let (k0, k1) = test_vectors::SIP_KEYS;
+    check_with(|| siphasher::sip::SipHasher13::new_with_keys(k0, k1), test_vectors::SIP13);
+}
+
+#[test]
+fn sip24() {
+    let (k0, k1) = test_vectors::SIP_KEYS;
+    check_with(|| siphasher::sip::SipHasher24::new_with_keys(k0, k1), test_vectors::SIP24);
+}
+
+#[test]
+fn highway() {
+    check_with(|| highway::HighwayHasher::new(highway::Key(test_vectors::HIGHWAY_KEY)), test_vectors::HIGHWAY);
+}
+
+#[test]
+fn seahash_fixed_uses_seeds() {
+    assert_ne!(calc::<seahash::SeaHasher>(b"seahash"), calc::<hashers::SeaHashFixed>(b"seahash"),
+        "SeaHasher ignores custom seeds");
+}
+
+#[test]
+fn seahash_fixed() {
+    for &input in test_vectors::SEAHASH_FIXED_INPUTS {
+        let expected = seahash::reference::hash_seeded(input,
+            0x243f6a8885a308d3, 0x13198a2e03707344, 0xa4093822299f31d0, 0x082efa98ec4e6c89);
+        assert_eq!(calc::<hashers::SeaHashFixed>(input), expected, "Hash mismatch for input {:?}",
+            String::from_utf8_lossy(input));
+    }
+}